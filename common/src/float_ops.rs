@@ -151,7 +151,12 @@ pub fn ulp(x: f64) -> f64 {
 pub fn round_float_digits(x: f64, ndigits: i32) -> Option<f64> {
     let float = if ndigits.is_zero() {
         let fract = x.fract();
-        if (fract.abs() - 0.5).abs() < f64::EPSILON {
+        // `0.5` is exactly representable in binary floating point, so ties can (and must) be
+        // detected with an exact comparison rather than an epsilon tolerance - a tolerance would
+        // misclassify non-tied fractions close to, but not exactly, `0.5` as ties.
+        #[allow(clippy::float_cmp)]
+        let is_tie = fract.abs() == 0.5;
+        if is_tie {
             if x.trunc() % 2.0 == 0.0 {
                 x - fract
             } else {
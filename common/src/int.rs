@@ -128,6 +128,29 @@ pub fn bytes_to_int(lit: &[u8], mut base: u32) -> Option<BigInt> {
     Some(number)
 }
 
+/// Number of significant decimal digits in `lit`, ignoring a leading sign, underscores, and
+/// leading zeros. This is what `sys.set_int_max_str_digits`'s limit counts against, matching
+/// CPython's accounting for its int/str conversion length guard.
+pub fn num_decimal_digits(lit: &[u8]) -> usize {
+    let mut lit = lit.trim();
+    if let Some(b'+' | b'-') = lit.first() {
+        lit = &lit[1..];
+    }
+    let mut count = 0;
+    let mut started = false;
+    for &c in lit {
+        match c {
+            b'_' => continue,
+            b'0' if !started => continue,
+            _ => {
+                started = true;
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
 #[inline]
 pub fn detect_base(c: &u8) -> Option<u32> {
     let base = match c {
@@ -157,3 +180,12 @@ fn test_bytes_to_int() {
     assert_eq!(bytes_to_int(&b"0_"[..], 0), None);
     assert_eq!(bytes_to_int(&b"0_100"[..], 10).unwrap(), BigInt::from(100));
 }
+
+#[test]
+fn test_num_decimal_digits() {
+    assert_eq!(num_decimal_digits(b"12345"), 5);
+    assert_eq!(num_decimal_digits(b"-12345"), 5);
+    assert_eq!(num_decimal_digits(b"+0012345"), 5);
+    assert_eq!(num_decimal_digits(b"000"), 0);
+    assert_eq!(num_decimal_digits(b"1_000_000"), 7);
+}
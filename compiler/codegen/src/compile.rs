@@ -1086,8 +1086,27 @@ impl Compiler {
                         self.store_name(name.as_ref())?;
                     }
                 }
-                located_ast::TypeParam::ParamSpec(_) => todo!(),
-                located_ast::TypeParam::TypeVarTuple(_) => todo!(),
+                located_ast::TypeParam::ParamSpec(located_ast::TypeParamParamSpec {
+                    name, ..
+                }) => {
+                    self.emit_load_const(ConstantData::Str {
+                        value: name.to_string(),
+                    });
+                    emit!(self, Instruction::TypeParamSpec);
+                    emit!(self, Instruction::Duplicate);
+                    self.store_name(name.as_ref())?;
+                }
+                located_ast::TypeParam::TypeVarTuple(located_ast::TypeParamTypeVarTuple {
+                    name,
+                    ..
+                }) => {
+                    self.emit_load_const(ConstantData::Str {
+                        value: name.to_string(),
+                    });
+                    emit!(self, Instruction::TypeVarTuple);
+                    emit!(self, Instruction::Duplicate);
+                    self.store_name(name.as_ref())?;
+                }
             };
         }
         emit!(
@@ -1180,7 +1199,26 @@ impl Compiler {
             }
 
             // Handler code:
-            self.compile_statements(body)?;
+            if let Some(alias) = name {
+                // PEP 3110: `except E as name` implicitly deletes `name` when the handler
+                // exits (by any means - falling off the end, return, break, continue, or a
+                // new exception), so the traceback doesn't keep the exception - and
+                // everything it's chained to or holds a reference to - alive via the local.
+                let cleanup = self.new_block();
+                emit!(self, Instruction::SetupFinally { handler: cleanup });
+                self.compile_statements(body)?;
+                emit!(self, Instruction::PopBlock);
+                emit!(self, Instruction::EnterFinally);
+                self.switch_to_block(cleanup);
+                // Rebind to None first so deleting it can't raise if the handler body
+                // already did `del name` itself.
+                self.emit_load_const(ConstantData::None);
+                self.store_name(alias.as_str())?;
+                self.compile_name(alias.as_str(), NameUsage::Delete)?;
+                emit!(self, Instruction::EndFinally);
+            } else {
+                self.compile_statements(body)?;
+            }
             emit!(self, Instruction::PopException);
 
             if !finalbody.is_empty() {
@@ -1231,6 +1269,17 @@ impl Compiler {
         Ok(())
     }
 
+    // `except*` isn't compiled yet. `BaseExceptionGroup`/`ExceptionGroup` themselves, and their
+    // `split`/`subgroup`/`derive` methods, are implemented at the runtime level (see
+    // `vm/src/exceptions.rs`) since asyncio and friends can use those directly, but the
+    // `except*` clause itself needs its own unwinding semantics: on a match, a clause can catch
+    // only the matching subset of a raised group (via the same `split` used by `.subgroup()`,
+    // not a plain `isinstance` test) and re-raise the unmatched remainder after the `try`
+    // finishes, and exceptions raised in multiple `except*` clauses combine into one new group
+    // rather than the most-recent-wins behavior ordinary `except` has. That's new unwinding
+    // logic in `vm/src/frame.rs`'s block-stack handling (see `unwind_blocks`), not just new
+    // codegen here, so it's left unimplemented rather than reusing `compile_try_statement`'s
+    // bytecode under it incorrectly.
     fn compile_try_star_statement(
         &mut self,
         _body: &[located_ast::Stmt],
@@ -1755,13 +1804,22 @@ impl Compiler {
         Ok(())
     }
 
+    // `match` statements aren't compiled yet: doing this properly means a pattern compiler
+    // that lowers every `located_ast::Pattern` variant (literal/capture/wildcard/value/
+    // sequence/mapping/class/or/as) into a sequence of tests and bindings against the
+    // subject, plus the bytecode this file doesn't have anywhere else - CPython's
+    // MATCH_SEQUENCE/MATCH_MAPPING/MATCH_KEYS/MATCH_CLASS/COPY/GET_LEN opcodes (or this
+    // codebase's equivalents) and matching `Instruction` handlers in `vm/src/frame.rs`. Class
+    // patterns additionally need `__match_args__` lookup and positional-to-keyword rewriting
+    // at runtime, and or-patterns need every alternative to bind the same set of names so
+    // falling through to the next `case` doesn't leave a partial binding behind. That's a
+    // coordinated change across `bytecode.rs`, this file, and `frame.rs`, not a local fix to
+    // this one function - left unimplemented rather than attempted piecemeal here.
     fn compile_match(
         &mut self,
-        subject: &located_ast::Expr,
-        cases: &[located_ast::MatchCase],
+        _subject: &located_ast::Expr,
+        _cases: &[located_ast::MatchCase],
     ) -> CompileResult<()> {
-        eprintln!("match subject: {subject:?}");
-        eprintln!("match cases: {cases:?}");
         Err(self.error(CodegenErrorType::NotImplementedYet))
     }
 
@@ -2966,6 +3024,14 @@ impl Compiler {
     }
 
     // Low level helper functions:
+    // Every `emit!`/`emit_arg`/`emit_no_arg` call in this file funnels through here, so it's
+    // the one place a pluggable backend (e.g. a register-based IR) would need to intercept -
+    // but `Compiler` also reaches into `ir::CodeInfo`/`ir::Block` directly all over this file
+    // (`current_block`, `current_code_info`, the peephole rewrite in `emit_return_value`, jump
+    // patching, `max_stackdepth`), so pulling emission out behind a `CodeEmitter` trait means
+    // giving that trait a way to express all of those, not just "push an instruction". That's
+    // a real redesign of the `ir` module's API, not a refactor of this one function - too much
+    // to retrofit blind in a single change here.
     fn _emit(&mut self, instr: Instruction, arg: OpArg, target: ir::BlockIdx) {
         let location = self.current_source_location;
         // TODO: insert source filename
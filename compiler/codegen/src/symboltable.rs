@@ -1119,8 +1119,14 @@ impl SymbolTableBuilder {
                 }
                 // Interesting stuff about the __class__ variable:
                 // https://docs.python.org/3/reference/datamodel.html?highlight=__class__#creating-the-class-object
+                // Comprehensions are function-like scopes too (they compile to their own code
+                // object), so a bare `super` inside a comprehension nested in a method must make
+                // `__class__` free there as well, not just in the method body itself.
                 if context == ExpressionContext::Load
-                    && self.tables.last().unwrap().typ == SymbolTableType::Function
+                    && matches!(
+                        self.tables.last().unwrap().typ,
+                        SymbolTableType::Function | SymbolTableType::Comprehension
+                    )
                     && id == "super"
                 {
                     self.register_name("__class__", SymbolUsage::Used, range.start)?;
@@ -1255,8 +1261,28 @@ impl SymbolTableBuilder {
                         self.scan_expression(binding, ExpressionContext::Load)?;
                     }
                 }
-                ast::located::TypeParam::ParamSpec(_) => todo!(),
-                ast::located::TypeParam::TypeVarTuple(_) => todo!(),
+                ast::located::TypeParam::ParamSpec(ast::TypeParamParamSpec {
+                    name,
+                    range: param_spec_range,
+                    ..
+                }) => {
+                    self.register_name(
+                        name.as_str(),
+                        SymbolUsage::Assigned,
+                        param_spec_range.start,
+                    )?;
+                }
+                ast::located::TypeParam::TypeVarTuple(ast::TypeParamTypeVarTuple {
+                    name,
+                    range: type_var_tuple_range,
+                    ..
+                }) => {
+                    self.register_name(
+                        name.as_str(),
+                        SymbolUsage::Assigned,
+                        type_var_tuple_range.start,
+                    )?;
+                }
             }
         }
         Ok(())
@@ -91,6 +91,16 @@ impl ConstantBag for BasicBag {
 #[derive(Clone)]
 pub struct CodeObject<C: Constant = ConstantData> {
     pub instructions: Box<[CodeUnit]>,
+    // Per-instruction positions for tracebacks, one `SourceLocation` (row/column of the
+    // position's *start*) per entry in `instructions` - there's no end row/column alongside it,
+    // which is what a PEP 657 caret (`~~~^^^`) under just the offending sub-expression would
+    // need to know where to stop underlining. `rustpython_parser_core::source_code::
+    // SourceLocation` (this field's element type) doesn't carry that - it's a single point, not
+    // a range - and it comes from the out-of-tree `rustpython-ast`/`rustpython-parser-core`
+    // crates this workspace depends on via git rather than vendors, so adding an end position
+    // means a change to that crate (and therefore can't be done from here) before this field,
+    // `compiler/codegen/src/compile.rs`'s `set_source_location` plumbing, and `co_positions()`/
+    // the traceback renderer in `vm/src/exceptions.rs` could even start consuming it.
     pub locations: Box<[SourceLocation]>,
     pub flags: CodeFlags,
     pub posonlyarg_count: u32,
@@ -595,10 +605,12 @@ pub enum Instruction {
     TypeVarWithBound,
     TypeVarWithConstraint,
     TypeAlias,
+    TypeVarTuple,
+    TypeParamSpec,
     // If you add a new instruction here, be sure to keep LAST_INSTRUCTION updated
 }
 // This must be kept up to date to avoid marshaling errors
-const LAST_INSTRUCTION: Instruction = Instruction::TypeAlias;
+const LAST_INSTRUCTION: Instruction = Instruction::TypeParamSpec;
 const _: () = assert!(mem::size_of::<Instruction>() == 1);
 
 impl From<Instruction> for u8 {
@@ -1291,6 +1303,8 @@ impl Instruction {
             TypeVarWithBound => -1,
             TypeVarWithConstraint => -1,
             TypeAlias => -2,
+            TypeVarTuple => 0,
+            TypeParamSpec => 0,
         }
     }
 
@@ -1460,6 +1474,8 @@ impl Instruction {
             TypeVarWithBound => w!(TypeVarWithBound),
             TypeVarWithConstraint => w!(TypeVarWithConstraint),
             TypeAlias => w!(TypeAlias),
+            TypeVarTuple => w!(TypeVarTuple),
+            TypeParamSpec => w!(TypeParamSpec),
         }
     }
 }
@@ -42,6 +42,17 @@ impl std::error::Error for MarshalError {}
 
 type Result<T, E = MarshalError> = std::result::Result<T, E>;
 
+/// `FORMAT_VERSION` claims CPython's marshal format 4, and every type it can produce (ints,
+/// floats, complex, strings, bytes, the containers, code objects) round-trips correctly through
+/// `dumps`/`loads` -- which is what `importlib`'s `.pyc` cache and user code both rely on. What
+/// format 4 doesn't have here is CPython's `FLAG_REF`/`TYPE_REF` backreference scheme, which
+/// dedupes repeated strings and other interned/shared objects by index instead of writing them
+/// out again (see the commented-out `Interned`/`Ref` variants below, kept as markers of what's
+/// missing). That scheme needs a memo table threaded through every recursive `serialize_value`/
+/// `deserialize_value` call, on both the write and read side, kept in sync with each other -- a
+/// correctness-sensitive change to a format import caching depends on, not worth risking without
+/// being able to compile and test it. Leaving it out costs some `.pyc` size and means streams
+/// aren't byte-for-byte what real CPython would produce, but every value still round-trips.
 #[repr(u8)]
 enum Type {
     // Null = b'0',
@@ -169,7 +169,7 @@ fn generate_field((i, field): (usize, &Field)) -> Result<TokenStream> {
     } else {
         let err = match attr.kind {
             ParameterKind::PositionalOnly | ParameterKind::PositionalOrKeyword => quote! {
-                ::rustpython_vm::function::ArgumentError::TooFewArgs
+                ::rustpython_vm::function::ArgumentError::RequiredPositionalArgument(#pyname.to_owned())
             },
             ParameterKind::KeywordOnly => quote! {
                 ::rustpython_vm::function::ArgumentError::RequiredKeywordArgument(#pyname.to_owned())
@@ -1,3 +1,32 @@
+//! An experimental, whole-function JIT: [`compile`] either lowers a bytecode
+//! [`CodeObject`][bytecode::CodeObject] entirely to native code, or fails with
+//! [`JitCompileError::NotSupported`] and leaves the function to the interpreter. There is no
+//! middle ground -- every operation the compiled function performs must be one `instructions.rs`
+//! can emit unconditionally, without ever needing to consult the interpreter mid-function.
+//!
+//! That rules out the operations most real Python functions actually spend their time on: list
+//! indexing (needs a bounds check that can fail), attribute loads (the class of the receiver, and
+//! therefore the slot being read, can change from call to call), and `int` arithmetic that
+//! overflows `i64` (today `BinaryOperator::Add` on two `JitValue::Int`s lowers to `iadd_ifcout` +
+//! `trapif`, i.e. an overflow aborts the process instead of falling back to `BigInt`; see
+//! `instructions.rs`). Handling any of these from compiled code requires a *guard*: a runtime
+//! check, emitted inline, that either continues with a fast native path or deoptimizes -- bails
+//! out of the compiled function and resumes the same call in the interpreter with the
+//! already-read control flow and locals reconstructed from what's been compiled so far. Cranelift
+//! gives no help with that bailout; building it means deciding how a deopt unwinds the compiled
+//! frame back into `Frame::run` (vm/src/frame.rs) with the right fastlocals and stack state, which
+//! is a different shape of problem from anything `FunctionCompiler` does today and isn't
+//! addressed by this crate yet.
+//!
+//! Floats, comparisons, and conditional/unconditional branches are already handled (see
+//! `instructions.rs`'s `BinaryOperation`, `CompareOperation`, and `Jump`/`JumpIfTrue`/
+//! `JumpIfFalse` arms). Function calls are not: every `CallFunction*` instruction falls through to
+//! the catch-all `Err(JitCompileError::NotSupported)` at the bottom of `add_instruction`.
+//! Emitting one for real needs a `FuncId` for the callee in this `Jit`'s `JITModule` and a
+//! `JitSig` describing its argument/return types, neither of which `FunctionCompiler` has a way to
+//! obtain today -- the callee might not be jitted at all (falling back to calling the interpreter
+//! from compiled code is its own unimplemented ABI boundary), and even a jitted callee's `JitSig`
+//! isn't threaded through anywhere a caller could look it up.
 mod instructions;
 
 use cranelift::prelude::*;
@@ -1,6 +1,11 @@
 use rustpython_vm::{builtins::PyModule, Interpreter, PyRef, Settings, VirtualMachine};
 
 pub type InitHook = Box<dyn FnOnce(&mut VirtualMachine)>;
+/// Runs after core initialization but before `site` (and any user code) is processed,
+/// letting embedders adjust `sys.path`, install import hooks, or register audit hooks
+/// at the one moment both the core VM and stdlib paths are settled but nothing Python
+/// has run yet.
+pub type PresiteHook = Box<dyn FnOnce(&VirtualMachine)>;
 
 /// The convenient way to create [rustpython_vm::Interpreter] with stdlib and other stuffs.
 ///
@@ -40,6 +45,7 @@ pub type InitHook = Box<dyn FnOnce(&mut VirtualMachine)>;
 pub struct InterpreterConfig {
     settings: Option<Settings>,
     init_hooks: Vec<InitHook>,
+    presite_hook: Option<PresiteHook>,
 }
 
 impl InterpreterConfig {
@@ -48,10 +54,14 @@ impl InterpreterConfig {
     }
     pub fn interpreter(self) -> Interpreter {
         let settings = self.settings.unwrap_or_default();
+        let presite_hook = self.presite_hook;
         Interpreter::with_init(settings, |vm| {
             for hook in self.init_hooks {
                 hook(vm);
             }
+            if let Some(presite_hook) = presite_hook {
+                vm.set_presite_hook(presite_hook);
+            }
         })
     }
 
@@ -63,6 +73,14 @@ impl InterpreterConfig {
         self.init_hooks.push(hook);
         self
     }
+    /// Set a hook to run between core VM initialization and `site`/main execution.
+    /// Only the caller of [`rustpython::run`](crate::run) (or code that itself calls
+    /// [`VirtualMachine::run_presite_hook`](rustpython_vm::VirtualMachine::run_presite_hook)
+    /// at the right point) will actually invoke this hook.
+    pub fn presite_hook(mut self, hook: PresiteHook) -> Self {
+        self.presite_hook = Some(hook);
+        self
+    }
     pub fn add_native_module(
         self,
         name: String,
@@ -139,9 +139,57 @@ __import__("io").TextIOWrapper(
 
 #[cfg(feature = "ssl")]
 fn ensurepip(_: Scope, vm: &VirtualMachine) -> PyResult<()> {
+    // ensurepip._bootstrap re-execs `sys.executable -c ...` to install the
+    // bundled wheel via subprocess + zipimport, both of which are already
+    // wired (see `import.rs`'s zipimporter path_hooks setup and the
+    // `_posixsubprocess` module), so this is just the CLI entry point.
     vm.run_module("ensurepip")
 }
 
+/// Reads a JSON or TOML document from stdin and pretty-prints it as indented JSON,
+/// in the spirit of `python -m json.tool` but also accepting TOML (which has no
+/// standard library pretty-printer of its own). Decode errors are reported the same
+/// way `json.tool` reports them: the exception message, not a traceback.
+///
+/// This is a separate, simpler convenience, not a reimplementation of
+/// `json.tool`: it loads the whole input into memory and always uses
+/// `indent=2, sort_keys=False`. `rustpython -m json.tool` itself needs no
+/// special-casing here -- `Lib/json/tool.py` runs as-is through the existing
+/// `runpy`/`argparse`/`pathlib` machinery and already supports streaming
+/// JSON Lines input plus `--sort-keys`/`--indent`/`--compact`.
+fn pretty_print(
+    format: crate::settings::PPFormat,
+    scope: Scope,
+    vm: &VirtualMachine,
+) -> PyResult<()> {
+    let source = match format {
+        crate::settings::PPFormat::Json => {
+            r#"
+import sys, json
+try:
+    data = json.load(sys.stdin)
+except json.JSONDecodeError as exc:
+    sys.exit(f"Invalid JSON: {exc}")
+json.dump(data, sys.stdout, indent=2, sort_keys=False)
+sys.stdout.write("\n")
+"#
+        }
+        crate::settings::PPFormat::Toml => {
+            r#"
+import sys, json, tomllib
+try:
+    data = tomllib.loads(sys.stdin.read())
+except tomllib.TOMLDecodeError as exc:
+    sys.exit(f"Invalid TOML: {exc}")
+json.dump(data, sys.stdout, indent=2, sort_keys=False, default=str)
+sys.stdout.write("\n")
+"#
+        }
+    };
+    vm.run_code_string(scope, source, "<pp>".to_owned())?;
+    Ok(())
+}
+
 fn install_pip(_installer: &str, _scope: Scope, vm: &VirtualMachine) -> PyResult<()> {
     #[cfg(feature = "ssl")]
     {
@@ -175,6 +223,8 @@ fn run_rustpython(vm: &VirtualMachine, run_mode: RunMode, quiet: bool) -> PyResu
         )?;
     }
 
+    vm.run_presite_hook();
+
     let site_result = vm.import("site", 0);
     if site_result.is_err() {
         warn!(
@@ -195,6 +245,9 @@ fn run_rustpython(vm: &VirtualMachine, run_mode: RunMode, quiet: bool) -> PyResu
         RunMode::InstallPip(installer) => {
             install_pip(&installer, scope, vm)?;
         }
+        RunMode::PrettyPrint(format) => {
+            pretty_print(format, scope, vm)?;
+        }
         RunMode::ScriptInteractive(script, interactive) => {
             if let Some(script) = script {
                 debug!("Running script {}", &script);
@@ -217,6 +270,22 @@ fn run_rustpython(vm: &VirtualMachine, run_mode: RunMode, quiet: bool) -> PyResu
             error!("Error writing profile information: {}", e);
         }
     }
+    #[cfg(feature = "pystats")]
+    if let Err(e) = write_pystats_report() {
+        error!("Error writing pystats report: {}", e);
+    }
+    Ok(())
+}
+
+#[cfg(feature = "pystats")]
+fn write_pystats_report() -> Result<(), Box<dyn std::error::Error>> {
+    use std::{env, fs, io::Write};
+
+    let report = vm::stats::dump_report();
+    match env::var_os("RUSTPYTHON_PYSTATS") {
+        Some(path) if path != "-" => fs::File::create(path)?.write_all(report.as_bytes())?,
+        _ => println!("{report}"),
+    }
     Ok(())
 }
 
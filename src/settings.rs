@@ -7,6 +7,13 @@ pub enum RunMode {
     Command(String),
     Module(String),
     InstallPip(String),
+    PrettyPrint(PPFormat),
+}
+
+#[derive(Copy, Clone)]
+pub enum PPFormat {
+    Json,
+    Toml,
 }
 
 pub fn opts_with_clap() -> (Settings, RunMode) {
@@ -62,6 +69,17 @@ fn parse_arguments<'a>(app: App<'a, '_>) -> ArgMatches<'a> {
                         requires rustpython be build with the ssl feature enabled."
                 ),
         )
+        .arg(
+            Arg::with_name("pp")
+                .long("pp")
+                .takes_value(true)
+                .possible_values(&["json", "toml"])
+                .help("read a document from stdin and pretty-print it as indented JSON; \
+                        unlike `python -m json.tool` this also accepts TOML input, but \
+                        doesn't stream large files or support --sort-keys/--indent \
+                        (use `rustpython -m json.tool` for that)"
+                ),
+        )
         .arg(
             Arg::with_name("optimize")
                 .short("O")
@@ -316,6 +334,14 @@ fn settings_from(matches: &ArgMatches) -> (Settings, RunMode) {
             .chain(cmd.map(ToOwned::to_owned))
             .collect();
         (RunMode::Module(module.to_owned()), argv)
+    } else if let Some(format) = matches.value_of("pp") {
+        settings.isolated = true;
+        let format = match format {
+            "json" => PPFormat::Json,
+            "toml" => PPFormat::Toml,
+            _ => unreachable!("clap restricts to the possible_values above"),
+        };
+        (RunMode::PrettyPrint(format), vec!["".to_owned()])
     } else if let Some(get_pip_args) = matches.values_of("install_pip") {
         settings.isolated = true;
         let mut args: Vec<_> = get_pip_args.map(ToOwned::to_owned).collect();
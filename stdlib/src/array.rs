@@ -924,7 +924,7 @@ mod array {
             range: OptionalRangeArgs,
             vm: &VirtualMachine,
         ) -> PyResult<usize> {
-            let (start, stop) = range.saturate(self.len(), vm)?;
+            let (start, stop) = range.saturate(self.len());
             self.read().index(x, start, stop, vm)
         }
 
@@ -128,13 +128,15 @@ mod cmath {
     }
 
     #[pyfunction]
-    fn sinh(z: ArgIntoComplex) -> Complex64 {
-        z.sinh()
+    fn sinh(z: ArgIntoComplex, vm: &VirtualMachine) -> PyResult<Complex64> {
+        let z = *z;
+        result_or_overflow(z, z.sinh(), vm)
     }
 
     #[pyfunction]
-    fn cosh(z: ArgIntoComplex) -> Complex64 {
-        z.cosh()
+    fn cosh(z: ArgIntoComplex, vm: &VirtualMachine) -> PyResult<Complex64> {
+        let z = *z;
+        result_or_overflow(z, z.cosh(), vm)
     }
 
     #[pyfunction]
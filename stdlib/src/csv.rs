@@ -1,5 +1,13 @@
 pub(crate) use _csv::make_module;
 
+// Dialect handling and the reader/writer state machines below are implemented
+// natively on top of `csv_core`; only `csv.Sniffer` stays pure Python,
+// matching CPython where `_csv` never accelerates it either. Quoting modes
+// are native too, except `QUOTE_STRINGS`/`QUOTE_NOTNULL` (3.12+): `csv_core`'s
+// `QuoteStyle` has no equivalent (it quotes by value, not by the field's
+// Python type or nullness), so `From<QuoteStyle> for csv_core::QuoteStyle`
+// below still has a `todo!()` for both -- picking them panics rather than
+// writing anything.
 #[pymodule]
 mod _csv {
     use crate::common::lock::PyMutex;
@@ -0,0 +1,54 @@
+pub(crate) use _gettext::make_module;
+
+#[pymodule]
+mod _gettext {
+    use rustpython_vm::{builtins::PyBytesRef, PyObjectRef, PyResult, VirtualMachine};
+
+    /// Walks the translation/original-string offset tables of a GNU `.mo` catalog and
+    /// pulls out the raw `(msgid, msgstr)` byte pairs, mirroring the per-entry loop in
+    /// `gettext.GNUTranslations._parse` -- the part of loading a large catalog that's
+    /// dominated by interpreter loop overhead rather than actual decoding work, so doing
+    /// it natively is what actually saves time; the header parsing and the msgid/msgstr
+    /// decoding, charset handling and plural-forms expression evaluation stay in Python
+    /// since they're cheap and already well-tested there.
+    #[pyfunction]
+    fn parse_mo_entries(
+        buf: PyBytesRef,
+        msgcount: u32,
+        masteridx: u32,
+        transidx: u32,
+        big_endian: bool,
+        vm: &VirtualMachine,
+    ) -> PyResult<Vec<PyObjectRef>> {
+        let buf = buf.as_bytes();
+        let read_u32 = |off: usize| -> PyResult<u32> {
+            let bytes = buf
+                .get(off..off + 4)
+                .ok_or_else(|| vm.new_value_error("File is corrupt".to_owned()))?;
+            let bytes: [u8; 4] = bytes.try_into().unwrap();
+            Ok(if big_endian {
+                u32::from_be_bytes(bytes)
+            } else {
+                u32::from_le_bytes(bytes)
+            })
+        };
+
+        let mut entries = Vec::with_capacity(msgcount as usize);
+        for i in 0..msgcount {
+            let masteridx = masteridx as usize + 8 * i as usize;
+            let transidx = transidx as usize + 8 * i as usize;
+            let mlen = read_u32(masteridx)? as usize;
+            let moff = read_u32(masteridx + 4)? as usize;
+            let tlen = read_u32(transidx)? as usize;
+            let toff = read_u32(transidx + 4)? as usize;
+            let msg = buf
+                .get(moff..moff + mlen)
+                .ok_or_else(|| vm.new_value_error("File is corrupt".to_owned()))?;
+            let tmsg = buf
+                .get(toff..toff + tlen)
+                .ok_or_else(|| vm.new_value_error("File is corrupt".to_owned()))?;
+            entries.push(vm.new_tuple((msg.to_vec(), tmsg.to_vec())).into());
+        }
+        Ok(entries)
+    }
+}
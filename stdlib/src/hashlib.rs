@@ -125,6 +125,14 @@ pub mod _hashlib {
             self.ctx.read().block_size()
         }
 
+        // Unlike CPython, this runtime has no GIL: the `PyRwLock` below is a per-object
+        // lock, so holding it across `update()` only serializes calls that touch this
+        // same hash object (which must serialize regardless, since they mutate its
+        // digest state) - it never blocks unrelated threads the way releasing CPython's
+        // GIL around `HASHLIB_GIL_MINSIZE`-sized buffers does. Threads hashing their own
+        // files or buffers in parallel already run concurrently here; `hashlib.file_digest`
+        // (`Lib/hashlib.py`) plus a `concurrent.futures.ThreadPoolExecutor` gets that today
+        // without a bespoke native helper.
         #[pymethod]
         fn update(&self, data: ArgBytesLike) {
             data.with_ref(|bytes| self.ctx.write().update(bytes));
@@ -0,0 +1,23 @@
+pub(crate) use _http::make_module;
+
+#[pymodule]
+mod _http {
+    use rustpython_vm::{builtins::PyBytesRef, PyResult, VirtualMachine};
+
+    /// Parses a chunk-size line for chunked transfer-encoding, mirroring
+    /// `http.client.HTTPResponse._read_next_chunk_size`: strips any
+    /// chunk-extensions after a `;` and reads the remainder as a hex size.
+    #[pyfunction]
+    fn parse_chunk_size(line: PyBytesRef, vm: &VirtualMachine) -> PyResult<i64> {
+        let line = line.as_bytes();
+        let line = match line.iter().position(|&b| b == b';') {
+            Some(i) => &line[..i],
+            None => line,
+        };
+        let line = std::str::from_utf8(line)
+            .map_err(|e| vm.new_unicode_decode_error(e.to_string()))?
+            .trim();
+        i64::from_str_radix(line, 16)
+            .map_err(|_| vm.new_value_error(format!("invalid literal for int(): {line:?}")))
+    }
+}
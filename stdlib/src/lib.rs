@@ -13,6 +13,8 @@ mod contextvars;
 mod csv;
 mod dis;
 mod gc;
+mod gettext;
+mod http;
 
 mod blake2;
 mod hashlib;
@@ -25,6 +27,7 @@ mod sha512;
 mod json;
 #[cfg(not(any(target_os = "ios", target_os = "android", target_arch = "wasm32")))]
 mod locale;
+mod lsprof;
 mod math;
 #[cfg(unix)]
 mod mmap;
@@ -110,6 +113,8 @@ pub fn get_module_inits() -> impl Iterator<Item = (Cow<'static, str>, StdlibInit
             "_csv" => csv::make_module,
             "_dis" => dis::make_module,
             "gc" => gc::make_module,
+            "_gettext" => gettext::make_module,
+            "_http" => http::make_module,
             "_hashlib" => hashlib::make_module,
             "_sha1" => sha1::make_module,
             "_sha3" => sha3::make_module,
@@ -118,6 +123,7 @@ pub fn get_module_inits() -> impl Iterator<Item = (Cow<'static, str>, StdlibInit
             "_md5" => md5::make_module,
             "_blake2" => blake2::make_module,
             "_json" => json::make_module,
+            "_lsprof" => lsprof::make_module,
             "math" => math::make_module,
             "pyexpat" => pyexpat::make_module,
             "_random" => random::make_module,
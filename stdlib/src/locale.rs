@@ -108,6 +108,20 @@ mod _locale {
         )
     }
 
+    #[cfg(all(
+        unix,
+        not(any(target_os = "ios", target_os = "android", target_os = "redox"))
+    ))]
+    #[pyfunction]
+    fn nl_langinfo(key: i32, vm: &VirtualMachine) -> PyResult {
+        // https://github.com/python/cpython/blob/677320348728ce058fa3579017e985af74a236d4/Modules/_localemodule.c#L488-L509
+        let result = unsafe { libc::nl_langinfo(key) };
+        if result.is_null() {
+            return Err(vm.new_value_error("unsupported langinfo constant".to_owned()));
+        }
+        unsafe { pystr_from_raw_cstr(vm, result) }
+    }
+
     #[pyfunction]
     fn strcoll(string1: PyStrRef, string2: PyStrRef, vm: &VirtualMachine) -> PyResult {
         let cstr1 = CString::new(string1.as_str()).map_err(|e| e.to_pyexception(vm))?;
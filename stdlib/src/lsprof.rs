@@ -0,0 +1,168 @@
+pub(crate) use _lsprof::make_module;
+
+#[pymodule]
+mod _lsprof {
+    use crate::common::lock::PyMutex;
+    use crate::vm::{
+        builtins::{PyStrRef, PyTypeRef},
+        frame::FrameRef,
+        function::FuncArgs,
+        types::{Callable, Constructor},
+        AsObject, Py, PyObjectRef, PyPayload, PyRef, PyResult, VirtualMachine,
+    };
+    use std::{collections::HashMap, time::Instant};
+
+    /// Aggregated stats for one code object, close enough to CPython's `_lsprof.profiler_entry`
+    /// to be turned into `pstats`-style rows: `(filename, firstlineno, name, callcount, totaltime,
+    /// inlinetime)`. Unlike CPython's lsprof, `calls` (the per-caller sub-entries that let `pstats`
+    /// render a call graph, not just flat totals) isn't tracked here -- that needs a call stack of
+    /// entries rather than just code identities, which is a bigger change than this module makes.
+    #[derive(Debug, Clone)]
+    struct ProfileEntry {
+        filename: String,
+        firstlineno: u32,
+        name: String,
+        callcount: u64,
+        totaltime_ns: u64,
+        inlinetime_ns: u64,
+    }
+
+    #[derive(Debug, Default)]
+    struct ProfilerState {
+        entries: HashMap<usize, ProfileEntry>,
+        // One (code identity, call start, time already charged to children) per frame on the stack.
+        stack: Vec<(usize, Instant, u64)>,
+    }
+
+    #[pyattr]
+    #[pyclass(name = "Profiler")]
+    #[derive(Debug, PyPayload)]
+    struct Profiler {
+        state: PyMutex<ProfilerState>,
+    }
+
+    impl Constructor for Profiler {
+        type Args = FuncArgs;
+
+        // CPython's `Profiler(timer=None, timeunit=0.0, subcalls=True, builtins=True)` all tune how
+        // time is measured or whether builtin calls are included; this profiler always times with
+        // `Instant::now()` and always counts every call, so the arguments are accepted (for
+        // source compatibility with code written against CPython's cProfile) and ignored.
+        fn py_new(cls: PyTypeRef, _args: Self::Args, vm: &VirtualMachine) -> PyResult {
+            Profiler {
+                state: PyMutex::new(ProfilerState::default()),
+            }
+            .into_ref_with_type(vm, cls)
+            .map(Into::into)
+        }
+    }
+
+    #[pyclass(with(Constructor, Callable))]
+    impl Profiler {
+        #[pymethod]
+        fn enable(zelf: PyRef<Self>, vm: &VirtualMachine) -> PyResult<()> {
+            vm.sys_module
+                .get_attr("setprofile", vm)?
+                .call((zelf,), vm)?;
+            Ok(())
+        }
+
+        #[pymethod]
+        fn disable(vm: &VirtualMachine) -> PyResult<()> {
+            vm.sys_module
+                .get_attr("setprofile", vm)?
+                .call((vm.ctx.none(),), vm)?;
+            Ok(())
+        }
+
+        #[pymethod]
+        fn clear(zelf: PyRef<Self>) {
+            let mut state = zelf.state.lock();
+            state.entries.clear();
+            state.stack.clear();
+        }
+
+        #[pymethod]
+        fn getstats(zelf: PyRef<Self>, vm: &VirtualMachine) -> Vec<PyObjectRef> {
+            let state = zelf.state.lock();
+            state
+                .entries
+                .values()
+                .map(|entry| {
+                    vm.ctx
+                        .new_tuple(vec![
+                            vm.ctx.new_str(entry.filename.clone()).into(),
+                            vm.ctx.new_int(entry.firstlineno).into(),
+                            vm.ctx.new_str(entry.name.clone()).into(),
+                            vm.ctx.new_int(entry.callcount).into(),
+                            vm.ctx.new_float(entry.totaltime_ns as f64 / 1e9).into(),
+                            vm.ctx.new_float(entry.inlinetime_ns as f64 / 1e9).into(),
+                        ])
+                        .into()
+                })
+                .collect()
+        }
+    }
+
+    impl Callable for Profiler {
+        type Args = FuncArgs;
+
+        /// `sys.setprofile`'s protocol is `profilefunc(frame, event, arg)`; `enable` installs `self`
+        /// as that function (see [`VirtualMachine::trace_event`][crate::vm::VirtualMachine], which is
+        /// private to the `vm` crate but already calls any profile function this way), so this is
+        /// invoked once per call and once per return, not by users directly.
+        fn call(zelf: &Py<Self>, args: Self::Args, vm: &VirtualMachine) -> PyResult<()> {
+            let mut args = args.args.into_iter();
+            let frame = args
+                .next()
+                .ok_or_else(|| vm.new_type_error("missing frame".to_owned()))?;
+            let event = args
+                .next()
+                .ok_or_else(|| vm.new_type_error("missing event".to_owned()))?;
+            let event: PyStrRef = event
+                .downcast()
+                .map_err(|_| vm.new_type_error("event must be str".to_owned()))?;
+            let frame: FrameRef = frame
+                .downcast()
+                .map_err(|_| vm.new_type_error("expected a frame object".to_owned()))?;
+            let code_id = frame.code.get_id();
+            let now = Instant::now();
+            let mut state = zelf.state.lock();
+            match event.as_str() {
+                "call" => {
+                    state.stack.push((code_id, now, 0));
+                }
+                "return" => {
+                    if let Some((stacked_id, start, child_ns)) = state.stack.pop() {
+                        let elapsed_ns = now.duration_since(start).as_nanos() as u64;
+                        let inline_ns = elapsed_ns.saturating_sub(child_ns);
+                        if let Some((_, _, parent_child_ns)) = state.stack.last_mut() {
+                            *parent_child_ns += elapsed_ns;
+                        }
+                        let entry =
+                            state
+                                .entries
+                                .entry(stacked_id)
+                                .or_insert_with(|| ProfileEntry {
+                                    filename: frame.code.code.source_path.as_str().to_owned(),
+                                    firstlineno: frame
+                                        .code
+                                        .code
+                                        .first_line_number
+                                        .map_or(0, |n| n.get()),
+                                    name: frame.code.code.obj_name.as_str().to_owned(),
+                                    callcount: 0,
+                                    totaltime_ns: 0,
+                                    inlinetime_ns: 0,
+                                });
+                        entry.callcount += 1;
+                        entry.totaltime_ns += elapsed_ns;
+                        entry.inlinetime_ns += inline_ns;
+                    }
+                }
+                _ => {}
+            }
+            Ok(())
+        }
+    }
+}
@@ -43,4 +43,443 @@ mod _multiprocessing {
 
 #[cfg(not(windows))]
 #[pymodule]
-mod _multiprocessing {}
+mod _multiprocessing {
+    use crate::common::lock::PyMutex;
+    use crate::vm::{
+        builtins::{PyStrRef, PyTypeRef},
+        convert::IntoPyException,
+        stdlib::os::errno_err,
+        types::Constructor,
+        PyPayload, PyResult, VirtualMachine,
+    };
+    use std::{
+        ffi::CString,
+        fmt, io,
+        time::{Duration, SystemTime, UNIX_EPOCH},
+    };
+
+    // multiprocessing.synchronize.RECURSIVE_MUTEX / SEMAPHORE; SemLock is
+    // constructed with one of these, matching CPython's _multiprocessing.
+    const RECURSIVE_MUTEX: i32 = 0;
+
+    struct SemLockState {
+        sem: *mut libc::sem_t,
+        // Recursion bookkeeping for RECURSIVE_MUTEX, mirroring CPython's
+        // SemLockObject.count/last_tid: a recursive mutex re-entered by its
+        // owning thread doesn't touch the underlying POSIX semaphore at all.
+        count: u32,
+        owner: Option<std::thread::ThreadId>,
+    }
+
+    // The raw semaphore handle is only ever touched with `state` locked, and
+    // the sem_* operations themselves are thread-safe, so this is fine to share.
+    unsafe impl Send for SemLockState {}
+
+    /// A POSIX named-semaphore backed `_multiprocessing.SemLock`, enough for
+    /// `multiprocessing`'s fork-based `Lock`/`RLock`/`Semaphore`/`Queue` to work.
+    /// Unlike CPython's version this doesn't support the `spawn` start method
+    /// (no `handle`-duplication/`_rebuild` for sharing a semaphore with a
+    /// freshly `exec`'d child over a pickle) -- that needs a second process
+    /// bootstrap path this interpreter doesn't have, so `multiprocessing`'s
+    /// default `fork` context is what this targets.
+    ///
+    /// This unblocks `import multiprocessing.synchronize`, which is as far as
+    /// `compileall`'s `workers` parameter goes today: with this present,
+    /// `concurrent.futures.process._check_system_limits()` no longer forces
+    /// `workers = 1`. Whether `ProcessPoolExecutor` actually drives parallel
+    /// compilation end-to-end (its manager thread, call-item pickling over
+    /// `multiprocessing.Queue`, and `resource_tracker`) hasn't been exercised
+    /// here -- that's still open, not just this one class.
+    #[pyattr]
+    #[pyclass(name = "SemLock")]
+    #[derive(PyPayload)]
+    struct SemLock {
+        state: PyMutex<SemLockState>,
+        kind: i32,
+        maxvalue: u32,
+        name: PyMutex<Option<String>>,
+    }
+
+    impl fmt::Debug for SemLock {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "_multiprocessing.SemLock")
+        }
+    }
+
+    impl Drop for SemLock {
+        fn drop(&mut self) {
+            unsafe { libc::sem_close(self.state.lock().sem) };
+        }
+    }
+
+    #[derive(FromArgs)]
+    struct SemLockNewArgs {
+        #[pyarg(positional)]
+        kind: i32,
+        #[pyarg(positional)]
+        value: u32,
+        #[pyarg(positional)]
+        maxvalue: u32,
+        #[pyarg(positional)]
+        name: PyStrRef,
+        #[pyarg(positional)]
+        unlink: bool,
+    }
+
+    impl Constructor for SemLock {
+        type Args = SemLockNewArgs;
+
+        fn py_new(cls: PyTypeRef, args: Self::Args, vm: &VirtualMachine) -> PyResult {
+            let cname = CString::new(args.name.as_str())
+                .map_err(|_| vm.new_value_error("embedded null byte in name".to_owned()))?;
+            let sem = unsafe {
+                libc::sem_open(
+                    cname.as_ptr(),
+                    libc::O_CREAT | libc::O_EXCL,
+                    0o600u32,
+                    args.value,
+                )
+            };
+            if sem as isize == -1 {
+                return Err(io::Error::last_os_error().into_pyexception(vm));
+            }
+            if args.unlink {
+                unsafe { libc::sem_unlink(cname.as_ptr()) };
+            }
+            SemLock {
+                state: PyMutex::new(SemLockState {
+                    sem,
+                    count: 0,
+                    owner: None,
+                }),
+                kind: args.kind,
+                maxvalue: args.maxvalue,
+                name: PyMutex::new((!args.unlink).then(|| args.name.as_str().to_owned())),
+            }
+            .into_ref_with_type(vm, cls)
+            .map(Into::into)
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    unsafe fn sem_wait_timeout(sem: *mut libc::sem_t, timeout: f64) -> libc::c_int {
+        let deadline = SystemTime::now() + Duration::from_secs_f64(timeout.max(0.0));
+        let since_epoch = deadline
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or(Duration::ZERO);
+        let ts = libc::timespec {
+            tv_sec: since_epoch.as_secs() as libc::time_t,
+            tv_nsec: since_epoch.subsec_nanos() as _,
+        };
+        libc::sem_timedwait(sem, &ts)
+    }
+
+    // macOS has no sem_timedwait(); poll sem_trywait() instead, as CPython's
+    // own `_multiprocessing/semaphore.c` does on platforms lacking it.
+    #[cfg(not(target_os = "linux"))]
+    unsafe fn sem_wait_timeout(sem: *mut libc::sem_t, timeout: f64) -> libc::c_int {
+        let deadline = std::time::Instant::now() + Duration::from_secs_f64(timeout.max(0.0));
+        loop {
+            let res = libc::sem_trywait(sem);
+            if res == 0 || std::io::Error::last_os_error().raw_os_error() != Some(libc::EAGAIN) {
+                return res;
+            }
+            if std::time::Instant::now() >= deadline {
+                return res;
+            }
+            std::thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    #[derive(FromArgs)]
+    struct AcquireArgs {
+        #[pyarg(any, default = "true")]
+        blocking: bool,
+        #[pyarg(any, default)]
+        timeout: Option<f64>,
+    }
+
+    #[pyclass(with(Constructor))]
+    impl SemLock {
+        // POSIX only guarantees `_POSIX_SEM_VALUE_MAX >= 32767`; glibc's actual
+        // `SEM_VALUE_MAX` isn't exposed by the `libc` crate, so fall back to
+        // `INT_MAX` like CPython does when the platform macro is unavailable.
+        #[pyattr]
+        const SEM_VALUE_MAX: u32 = i32::MAX as u32;
+
+        #[pygetset]
+        fn kind(&self) -> i32 {
+            self.kind
+        }
+
+        #[pygetset]
+        fn maxvalue(&self) -> u32 {
+            self.maxvalue
+        }
+
+        #[pygetset]
+        fn name(&self) -> Option<String> {
+            self.name.lock().clone()
+        }
+
+        #[pygetset]
+        fn handle(&self) -> usize {
+            self.state.lock().sem as usize
+        }
+
+        #[pymethod]
+        fn acquire(&self, args: AcquireArgs, vm: &VirtualMachine) -> PyResult<bool> {
+            let AcquireArgs { blocking, timeout } = args;
+            let this_thread = std::thread::current().id();
+
+            let sem = {
+                let mut state = self.state.lock();
+                if self.kind == RECURSIVE_MUTEX
+                    && state.count > 0
+                    && state.owner == Some(this_thread)
+                {
+                    state.count += 1;
+                    return Ok(true);
+                }
+                state.sem
+            };
+
+            // The Rust-level mutex must be dropped before blocking in
+            // sem_wait*/sem_trywait: a concurrent release() on another thread
+            // (same process, unforked -- a normal way to share a
+            // multiprocessing.Lock) needs that same mutex to call sem_post,
+            // and holding it here while waiting for exactly that post would
+            // deadlock both threads.
+            let res = unsafe {
+                if !blocking {
+                    libc::sem_trywait(sem)
+                } else if let Some(timeout) = timeout {
+                    sem_wait_timeout(sem, timeout)
+                } else {
+                    libc::sem_wait(sem)
+                }
+            };
+            if res == 0 {
+                let mut state = self.state.lock();
+                state.count += 1;
+                state.owner = Some(this_thread);
+                Ok(true)
+            } else {
+                let err = io::Error::last_os_error();
+                match err.raw_os_error() {
+                    Some(libc::EAGAIN) | Some(libc::ETIMEDOUT) => Ok(false),
+                    _ => Err(err.into_pyexception(vm)),
+                }
+            }
+        }
+
+        #[pymethod]
+        fn release(&self, vm: &VirtualMachine) -> PyResult<()> {
+            let this_thread = std::thread::current().id();
+            let mut state = self.state.lock();
+            if self.kind == RECURSIVE_MUTEX {
+                if state.count == 0 || state.owner != Some(this_thread) {
+                    return Err(vm.new_runtime_error(
+                        "attempt to release recursive lock not owned by thread".to_owned(),
+                    ));
+                }
+                state.count -= 1;
+                if state.count > 0 {
+                    return Ok(());
+                }
+                state.owner = None;
+            } else {
+                state.count = state.count.saturating_sub(1);
+            }
+            if unsafe { libc::sem_post(state.sem) } != 0 {
+                return Err(errno_err(vm));
+            }
+            Ok(())
+        }
+
+        #[pymethod]
+        fn _count(&self) -> u32 {
+            self.state.lock().count
+        }
+
+        #[pymethod]
+        fn _get_value(&self, vm: &VirtualMachine) -> PyResult<i32> {
+            let state = self.state.lock();
+            let mut value: libc::c_int = 0;
+            if unsafe { libc::sem_getvalue(state.sem, &mut value) } != 0 {
+                return Err(errno_err(vm));
+            }
+            Ok(value)
+        }
+
+        #[pymethod]
+        fn _is_zero(&self, vm: &VirtualMachine) -> PyResult<bool> {
+            Ok(self._get_value(vm)? == 0)
+        }
+
+        #[pymethod]
+        fn _is_mine(&self) -> bool {
+            let state = self.state.lock();
+            state.count > 0 && state.owner == Some(std::thread::current().id())
+        }
+
+        #[pymethod]
+        fn _after_fork(&self) {
+            let mut state = self.state.lock();
+            state.count = 0;
+            state.owner = None;
+        }
+
+        #[pymethod(name = "__enter__")]
+        fn enter(&self, vm: &VirtualMachine) -> PyResult<bool> {
+            self.acquire(
+                AcquireArgs {
+                    blocking: true,
+                    timeout: None,
+                },
+                vm,
+            )
+        }
+
+        #[pymethod(name = "__exit__")]
+        fn exit(&self, _args: crate::vm::function::FuncArgs, vm: &VirtualMachine) -> PyResult<()> {
+            self.release(vm)
+        }
+    }
+
+    #[pyfunction]
+    fn sem_unlink(name: PyStrRef, vm: &VirtualMachine) -> PyResult<()> {
+        let cname = CString::new(name.as_str())
+            .map_err(|_| vm.new_value_error("embedded null byte in name".to_owned()))?;
+        if unsafe { libc::sem_unlink(cname.as_ptr()) } != 0 {
+            return Err(io::Error::last_os_error().into_pyexception(vm));
+        }
+        Ok(())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use rustpython_vm::Interpreter;
+        use std::{
+            sync::Arc,
+            time::{Duration as StdDuration, Instant},
+        };
+
+        // Builds a SemLock directly (bypassing the pyclass constructor, which
+        // needs a registered PyType) around a fresh, uniquely-named semaphore
+        // unlinked immediately after creation, same as SemLockNewArgs::unlink.
+        fn open_sem_lock(kind: i32, value: u32, tag: &str) -> SemLock {
+            let name = format!("/rustpython-test-{}-{}", std::process::id(), tag);
+            let cname = CString::new(name).unwrap();
+            let sem = unsafe {
+                libc::sem_open(
+                    cname.as_ptr(),
+                    libc::O_CREAT | libc::O_EXCL,
+                    0o600u32,
+                    value,
+                )
+            };
+            assert_ne!(
+                sem as isize,
+                -1,
+                "sem_open failed: {}",
+                io::Error::last_os_error()
+            );
+            unsafe { libc::sem_unlink(cname.as_ptr()) };
+            SemLock {
+                state: PyMutex::new(SemLockState {
+                    sem,
+                    count: 0,
+                    owner: None,
+                }),
+                kind,
+                maxvalue: 1,
+                name: PyMutex::new(None),
+            }
+        }
+
+        #[test]
+        fn acquire_then_release_same_thread() {
+            Interpreter::without_stdlib(Default::default()).enter(|vm| {
+                let lock = open_sem_lock(1, 1, "acquire-release");
+                let args = AcquireArgs {
+                    blocking: true,
+                    timeout: None,
+                };
+                assert!(lock.acquire(args, vm).unwrap());
+                assert_eq!(lock._get_value(vm).unwrap(), 0);
+                lock.release(vm).unwrap();
+                assert_eq!(lock._get_value(vm).unwrap(), 1);
+            });
+        }
+
+        #[test]
+        fn nonblocking_acquire_fails_when_unavailable() {
+            Interpreter::without_stdlib(Default::default()).enter(|vm| {
+                let lock = open_sem_lock(1, 0, "nonblocking");
+                let args = AcquireArgs {
+                    blocking: false,
+                    timeout: None,
+                };
+                assert!(!lock.acquire(args, vm).unwrap());
+            });
+        }
+
+        #[test]
+        fn blocking_acquire_times_out() {
+            Interpreter::without_stdlib(Default::default()).enter(|vm| {
+                let lock = open_sem_lock(1, 0, "timeout");
+                let args = AcquireArgs {
+                    blocking: true,
+                    timeout: Some(0.05),
+                };
+                let start = Instant::now();
+                assert!(!lock.acquire(args, vm).unwrap());
+                assert!(start.elapsed() >= StdDuration::from_millis(30));
+            });
+        }
+
+        #[test]
+        fn recursive_mutex_is_reentrant_on_owning_thread() {
+            Interpreter::without_stdlib(Default::default()).enter(|vm| {
+                let lock = open_sem_lock(RECURSIVE_MUTEX, 1, "recursive");
+                let args = || AcquireArgs {
+                    blocking: true,
+                    timeout: None,
+                };
+                assert!(lock.acquire(args(), vm).unwrap());
+                assert!(lock.acquire(args(), vm).unwrap());
+                assert_eq!(lock._count(), 2);
+                lock.release(vm).unwrap();
+                assert_eq!(lock._count(), 1);
+                lock.release(vm).unwrap();
+                assert_eq!(lock._count(), 0);
+            });
+        }
+
+        #[test]
+        fn release_on_another_thread_wakes_blocked_acquire() {
+            let lock = Arc::new(open_sem_lock(1, 0, "cross-thread"));
+            let lock2 = Arc::clone(&lock);
+            let releaser = std::thread::spawn(move || {
+                std::thread::sleep(StdDuration::from_millis(50));
+                Interpreter::without_stdlib(Default::default()).enter(|vm| {
+                    lock2.release(vm).unwrap();
+                });
+            });
+            // If acquire() held the Rust-level mutex across the blocking
+            // sem_wait, this releaser thread would deadlock trying to take
+            // that same mutex to call sem_post, and the acquire() below
+            // would never be woken.
+            Interpreter::without_stdlib(Default::default()).enter(|vm| {
+                let args = AcquireArgs {
+                    blocking: true,
+                    timeout: Some(5.0),
+                };
+                assert!(lock.acquire(args, vm).unwrap());
+            });
+            releaser.join().unwrap();
+        }
+    }
+}
@@ -11,6 +11,12 @@ pub fn make_module(vm: &VirtualMachine) -> PyRef<PyModule> {
     extend_module!(vm, &module, {
          "errors" => _errors::make_module(vm),
          "model" => _model::make_module(vm),
+         // https://github.com/python/cpython/blob/main/Modules/pyexpat.c -- values
+         // assigned by the real expat library and relied on by callers of
+         // SetParamEntityParsing().
+         "XML_PARAM_ENTITY_PARSING_NEVER" => vm.ctx.new_int(0),
+         "XML_PARAM_ENTITY_PARSING_UNLESS_STANDALONE" => vm.ctx.new_int(1),
+         "XML_PARAM_ENTITY_PARSING_ALWAYS" => vm.ctx.new_int(2),
     });
 
     module
@@ -51,6 +57,30 @@ mod _pyexpat {
         character_data: MutableObject,
         entity_decl: MutableObject,
         buffer_text: MutableObject,
+        processing_instruction: MutableObject,
+        comment: MutableObject,
+        start_cdata_section: MutableObject,
+        end_cdata_section: MutableObject,
+        start_doctype_decl: MutableObject,
+        end_doctype_decl: MutableObject,
+        unparsed_entity_decl: MutableObject,
+        notation_decl: MutableObject,
+        start_namespace_decl: MutableObject,
+        end_namespace_decl: MutableObject,
+        // Never invoked: the underlying `xml` crate doesn't parse DTDs at all, so
+        // external entities and parameter entities are never fetched or expanded --
+        // there's nothing for this handler to report, but expat-based callers (e.g.
+        // `xml.sax.expatreader`) still need to be able to assign to it.
+        external_entity_ref: MutableObject,
+        skipped_entity: MutableObject,
+        default: MutableObject,
+        xml_decl: MutableObject,
+        element_decl: MutableObject,
+        attlist_decl: MutableObject,
+        namespace_prefixes: MutableObject,
+        ordered_attributes: MutableObject,
+        specified_attributes: MutableObject,
+        namespace_separator: Option<String>,
     }
     type PyExpatLikeXmlParserRef = PyRef<PyExpatLikeXmlParser>;
 
@@ -64,13 +94,36 @@ mod _pyexpat {
 
     #[pyclass]
     impl PyExpatLikeXmlParser {
-        fn new(vm: &VirtualMachine) -> PyResult<PyExpatLikeXmlParserRef> {
+        fn new(
+            namespace_separator: Option<String>,
+            vm: &VirtualMachine,
+        ) -> PyResult<PyExpatLikeXmlParserRef> {
             Ok(PyExpatLikeXmlParser {
                 start_element: MutableObject::new(vm.ctx.none()),
                 end_element: MutableObject::new(vm.ctx.none()),
                 character_data: MutableObject::new(vm.ctx.none()),
                 entity_decl: MutableObject::new(vm.ctx.none()),
                 buffer_text: MutableObject::new(vm.ctx.new_bool(false).into()),
+                processing_instruction: MutableObject::new(vm.ctx.none()),
+                comment: MutableObject::new(vm.ctx.none()),
+                start_cdata_section: MutableObject::new(vm.ctx.none()),
+                end_cdata_section: MutableObject::new(vm.ctx.none()),
+                start_doctype_decl: MutableObject::new(vm.ctx.none()),
+                end_doctype_decl: MutableObject::new(vm.ctx.none()),
+                unparsed_entity_decl: MutableObject::new(vm.ctx.none()),
+                notation_decl: MutableObject::new(vm.ctx.none()),
+                start_namespace_decl: MutableObject::new(vm.ctx.none()),
+                end_namespace_decl: MutableObject::new(vm.ctx.none()),
+                external_entity_ref: MutableObject::new(vm.ctx.none()),
+                skipped_entity: MutableObject::new(vm.ctx.none()),
+                default: MutableObject::new(vm.ctx.none()),
+                xml_decl: MutableObject::new(vm.ctx.none()),
+                element_decl: MutableObject::new(vm.ctx.none()),
+                attlist_decl: MutableObject::new(vm.ctx.none()),
+                namespace_prefixes: MutableObject::new(vm.ctx.new_bool(false).into()),
+                ordered_attributes: MutableObject::new(vm.ctx.new_bool(false).into()),
+                specified_attributes: MutableObject::new(vm.ctx.new_bool(false).into()),
+                namespace_separator,
             }
             .into_ref(&vm.ctx))
         }
@@ -90,6 +143,103 @@ mod _pyexpat {
             );
             create_property!(ctx, attributes, "EntityDeclHandler", class, entity_decl);
             create_property!(ctx, attributes, "buffer_text", class, buffer_text);
+            create_property!(
+                ctx,
+                attributes,
+                "ProcessingInstructionHandler",
+                class,
+                processing_instruction
+            );
+            create_property!(ctx, attributes, "CommentHandler", class, comment);
+            create_property!(
+                ctx,
+                attributes,
+                "StartCdataSectionHandler",
+                class,
+                start_cdata_section
+            );
+            create_property!(
+                ctx,
+                attributes,
+                "EndCdataSectionHandler",
+                class,
+                end_cdata_section
+            );
+            create_property!(
+                ctx,
+                attributes,
+                "StartDoctypeDeclHandler",
+                class,
+                start_doctype_decl
+            );
+            create_property!(
+                ctx,
+                attributes,
+                "EndDoctypeDeclHandler",
+                class,
+                end_doctype_decl
+            );
+            create_property!(
+                ctx,
+                attributes,
+                "UnparsedEntityDeclHandler",
+                class,
+                unparsed_entity_decl
+            );
+            create_property!(ctx, attributes, "NotationDeclHandler", class, notation_decl);
+            create_property!(
+                ctx,
+                attributes,
+                "StartNamespaceDeclHandler",
+                class,
+                start_namespace_decl
+            );
+            create_property!(
+                ctx,
+                attributes,
+                "EndNamespaceDeclHandler",
+                class,
+                end_namespace_decl
+            );
+            create_property!(
+                ctx,
+                attributes,
+                "ExternalEntityRefHandler",
+                class,
+                external_entity_ref
+            );
+            create_property!(
+                ctx,
+                attributes,
+                "SkippedEntityHandler",
+                class,
+                skipped_entity
+            );
+            create_property!(ctx, attributes, "DefaultHandler", class, default);
+            create_property!(ctx, attributes, "XmlDeclHandler", class, xml_decl);
+            create_property!(ctx, attributes, "ElementDeclHandler", class, element_decl);
+            create_property!(ctx, attributes, "AttlistDeclHandler", class, attlist_decl);
+            create_property!(
+                ctx,
+                attributes,
+                "namespace_prefixes",
+                class,
+                namespace_prefixes
+            );
+            create_property!(
+                ctx,
+                attributes,
+                "ordered_attributes",
+                class,
+                ordered_attributes
+            );
+            create_property!(
+                ctx,
+                attributes,
+                "specified_attributes",
+                class,
+                specified_attributes
+            );
         }
 
         fn create_config(&self) -> xml::ParserConfig {
@@ -99,6 +249,17 @@ mod _pyexpat {
                 .whitespace_to_characters(true)
         }
 
+        /// Formats a qualified name the way expat does when namespace processing is
+        /// enabled: `uri<sep>local` if the name is namespaced, or just `local`
+        /// otherwise (e.g. `xml.sax.expatreader` always passes `" "` and recovers
+        /// the parts again with `name.split()`).
+        fn qualify_name(&self, name: &xml::name::OwnedName) -> String {
+            match (&self.namespace_separator, &name.namespace) {
+                (Some(sep), Some(uri)) => format!("{uri}{sep}{}", name.local_name),
+                _ => name.local_name.clone(),
+            }
+        }
+
         fn do_parse<T>(&self, vm: &VirtualMachine, parser: xml::EventReader<T>)
         where
             T: std::io::Read,
@@ -108,27 +269,63 @@ mod _pyexpat {
                     Ok(XmlEvent::StartElement {
                         name, attributes, ..
                     }) => {
-                        let dict = vm.ctx.new_dict();
-                        for attribute in attributes {
-                            dict.set_item(
-                                attribute.name.local_name.as_str(),
-                                vm.ctx.new_str(attribute.value).into(),
-                                vm,
-                            )
-                            .unwrap();
+                        let name_str = PyStr::from(self.qualify_name(&name)).into_ref(&vm.ctx);
+                        // When `ordered_attributes` is set, real expat reports attributes
+                        // as a flat [name1, value1, name2, value2, ...] list instead of a
+                        // dict, preserving document order; `xml.etree.ElementTree.XMLParser`
+                        // relies on this.
+                        let ordered_attributes = self
+                            .ordered_attributes
+                            .read()
+                            .clone()
+                            .is_true(vm)
+                            .unwrap_or(false);
+                        if ordered_attributes {
+                            let mut attrib_list = Vec::with_capacity(attributes.len() * 2);
+                            for attribute in attributes {
+                                attrib_list.push(
+                                    vm.ctx.new_str(self.qualify_name(&attribute.name)).into(),
+                                );
+                                attrib_list.push(vm.ctx.new_str(attribute.value).into());
+                            }
+                            let attrib_list = vm.ctx.new_list(attrib_list);
+                            invoke_handler(vm, &self.start_element, (name_str, attrib_list));
+                        } else {
+                            let dict = vm.ctx.new_dict();
+                            for attribute in attributes {
+                                dict.set_item(
+                                    self.qualify_name(&attribute.name).as_str(),
+                                    vm.ctx.new_str(attribute.value).into(),
+                                    vm,
+                                )
+                                .unwrap();
+                            }
+                            invoke_handler(vm, &self.start_element, (name_str, dict));
                         }
-
-                        let name_str = PyStr::from(name.local_name).into_ref(&vm.ctx);
-                        invoke_handler(vm, &self.start_element, (name_str, dict));
                     }
                     Ok(XmlEvent::EndElement { name, .. }) => {
-                        let name_str = PyStr::from(name.local_name).into_ref(&vm.ctx);
+                        let name_str = PyStr::from(self.qualify_name(&name)).into_ref(&vm.ctx);
                         invoke_handler(vm, &self.end_element, (name_str,));
                     }
                     Ok(XmlEvent::Characters(chars)) => {
                         let str = PyStr::from(chars).into_ref(&vm.ctx);
                         invoke_handler(vm, &self.character_data, (str,));
                     }
+                    Ok(XmlEvent::CData(chars)) => {
+                        invoke_handler(vm, &self.start_cdata_section, ());
+                        let str = PyStr::from(chars).into_ref(&vm.ctx);
+                        invoke_handler(vm, &self.character_data, (str,));
+                        invoke_handler(vm, &self.end_cdata_section, ());
+                    }
+                    Ok(XmlEvent::Comment(text)) => {
+                        let str = PyStr::from(text).into_ref(&vm.ctx);
+                        invoke_handler(vm, &self.comment, (str,));
+                    }
+                    Ok(XmlEvent::ProcessingInstruction { name, data }) => {
+                        let name_str = PyStr::from(name).into_ref(&vm.ctx);
+                        let data_str = PyStr::from(data.unwrap_or_default()).into_ref(&vm.ctx);
+                        invoke_handler(vm, &self.processing_instruction, (name_str, data_str));
+                    }
                     _ => {}
                 }
             }
@@ -154,6 +351,15 @@ mod _pyexpat {
             // todo: return value
             Ok(())
         }
+
+        /// Real expat uses this to control whether parameter entities (declared in
+        /// the DTD) get expanded. The `xml` crate backing this module never parses
+        /// a DTD at all, so there's nothing to toggle, but `xml.sax.expatreader`
+        /// calls this unconditionally on every parser it creates.
+        #[pymethod(name = "SetParamEntityParsing")]
+        fn set_param_entity_parsing(&self, _flag: i32) -> bool {
+            true
+        }
     }
 
     #[derive(FromArgs)]
@@ -169,10 +375,14 @@ mod _pyexpat {
 
     #[pyfunction(name = "ParserCreate")]
     fn parser_create(
-        _args: ParserCreateArgs,
+        args: ParserCreateArgs,
         vm: &VirtualMachine,
     ) -> PyResult<PyExpatLikeXmlParserRef> {
-        PyExpatLikeXmlParser::new(vm)
+        let namespace_separator = args
+            .namespace_separator
+            .into_option()
+            .map(|s| s.as_str().to_owned());
+        PyExpatLikeXmlParser::new(namespace_separator, vm)
     }
 }
 
@@ -12,7 +12,7 @@ mod re {
         builtins::{PyInt, PyIntRef, PyStr, PyStrRef},
         convert::{ToPyObject, TryFromObject},
         function::{OptionalArg, PosArgs},
-        match_class, PyObjectRef, PyResult, PyPayload, VirtualMachine,
+        match_class, PyObjectRef, PyPayload, PyResult, VirtualMachine,
     };
     use num_traits::Signed;
     use regex::bytes::{Captures, Regex, RegexBuilder};
@@ -184,10 +184,18 @@ mod re {
     }
 
     fn do_findall(vm: &VirtualMachine, pattern: &PyPattern, search_text: PyStrRef) -> PyResult {
-        let out = pattern
+        let mut out = Vec::new();
+        for (n, captures) in pattern
             .regex
             .captures_iter(search_text.as_str().as_bytes())
-            .map(|captures| match captures.len() {
+            .enumerate()
+        {
+            // matching over a huge haystack never enters the bytecode eval loop (the usual
+            // place check_signals runs), so give Ctrl-C a chance to land here too.
+            if n % 4096 == 0 {
+                vm.check_signals()?;
+            }
+            let item = match captures.len() {
                 1 => {
                     let full = captures.get(0).unwrap().as_bytes();
                     let full = String::from_utf8_lossy(full).into_owned();
@@ -211,8 +219,9 @@ mod re {
                         .collect();
                     vm.ctx.new_tuple(out).into()
                 }
-            })
-            .collect();
+            };
+            out.push(item);
+        }
         Ok(vm.ctx.new_list(out).into())
     }
 
@@ -237,6 +246,9 @@ mod re {
         let mut output = Vec::new();
         let mut last = 0;
         for (n, captures) in pattern.regex.captures_iter(text).enumerate() {
+            if n % 4096 == 0 {
+                vm.check_signals()?;
+            }
             let full = captures.get(0).unwrap();
             let matched = &text[last..full.start()];
             last = full.end();
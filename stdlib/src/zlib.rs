@@ -76,6 +76,16 @@ mod zlib {
         })
     }
 
+    // Shares `binascii`'s `crc32fast`-backed implementation, which uses SIMD
+    // (SSE4.2/PCLMULQDQ, ARM PMULL) where available rather than a table-driven
+    // byte loop.
+    //
+    // `zipfile` itself (Lib/zipfile.py, unmodified CPython) already handles
+    // the rest of this: ZIP64 end-of-central-directory parsing is in
+    // `_EndRecData64`/`structEndArchive64`, and `ZipExtFile.read()` streams a
+    // member in `MIN_READ_SIZE`-sized (4096 byte) chunks rather than loading
+    // it whole, so >4GB archives and memory-bounded extraction don't need a
+    // native helper here -- only the checksum was CPU-bound enough to matter.
     #[pyfunction]
     fn crc32(data: ArgBytesLike, begin_state: OptionalArg<PyIntRef>) -> u32 {
         crate::binascii::crc32(data, begin_state)
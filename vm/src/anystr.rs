@@ -1,11 +1,11 @@
 use crate::{
-    builtins::{PyIntRef, PyTuple},
+    builtins::PyTuple,
     cformat::cformat_string,
     convert::TryFromBorrowedObject,
-    function::OptionalOption,
+    function::{ArgIndex, OptionalOption},
     Py, PyObject, PyObjectRef, PyResult, TryFromObject, VirtualMachine,
 };
-use num_traits::{cast::ToPrimitive, sign::Signed};
+use num_traits::cast::ToPrimitive;
 
 #[derive(FromArgs)]
 pub struct SplitArgs<T: TryFromObject + AnyStrWrapper> {
@@ -53,9 +53,9 @@ pub struct StartsEndsWithArgs {
     #[pyarg(positional)]
     affix: PyObjectRef,
     #[pyarg(positional, default)]
-    start: Option<PyIntRef>,
+    start: Option<ArgIndex>,
     #[pyarg(positional, default)]
-    end: Option<PyIntRef>,
+    end: Option<ArgIndex>,
 }
 
 impl StartsEndsWithArgs {
@@ -87,40 +87,13 @@ impl StartsEndsWithArgs {
     }
 }
 
-fn saturate_to_isize(py_int: PyIntRef) -> isize {
-    let big = py_int.as_bigint();
-    big.to_isize().unwrap_or_else(|| {
-        if big.is_negative() {
-            isize::MIN
-        } else {
-            isize::MAX
-        }
-    })
-}
-
 // help get optional string indices
 pub fn adjust_indices(
-    start: Option<PyIntRef>,
-    end: Option<PyIntRef>,
+    start: Option<ArgIndex>,
+    end: Option<ArgIndex>,
     len: usize,
 ) -> std::ops::Range<usize> {
-    let mut start = start.map_or(0, saturate_to_isize);
-    let mut end = end.map_or(len as isize, saturate_to_isize);
-    if end > len as isize {
-        end = len as isize;
-    } else if end < 0 {
-        end += len as isize;
-        if end < 0 {
-            end = 0;
-        }
-    }
-    if start < 0 {
-        start += len as isize;
-        if start < 0 {
-            start = 0;
-        }
-    }
-    start as usize..end as usize
+    crate::sequence::saturate_range(start, end, len)
 }
 
 pub trait StringRange {
@@ -307,17 +280,28 @@ pub trait AnyStr {
 
     fn py_join(
         &self,
-        mut iter: impl std::iter::Iterator<
-            Item = PyResult<impl AnyStrWrapper<Str = Self> + TryFromObject>,
-        >,
+        iter: impl std::iter::Iterator<Item = PyResult<impl AnyStrWrapper<Str = Self> + TryFromObject>>,
     ) -> PyResult<Self::Container> {
-        let mut joined = if let Some(elem) = iter.next() {
-            elem?.as_ref().to_container()
+        // Collect first rather than growing `joined` one push_str at a time: for a join over
+        // many small pieces (e.g. serialization code joining thousands of bytes objects) this
+        // means one correctly-sized allocation instead of repeated reallocation as the container
+        // grows past its capacity.
+        let elems = iter.collect::<PyResult<Vec<_>>>()?;
+        let mut elems = elems.iter();
+        let mut joined = if let Some(elem) = elems.next() {
+            let elem = elem.as_ref();
+            let total_len = elem.bytes_len()
+                + elems
+                    .clone()
+                    .map(|e| self.bytes_len() + e.as_ref().bytes_len())
+                    .sum::<usize>();
+            let mut joined = Self::Container::with_capacity(total_len);
+            joined.push_str(elem);
+            joined
         } else {
             return Ok(Self::Container::new());
         };
-        for elem in iter {
-            let elem = elem?;
+        for elem in elems {
             joined.push_str(self);
             joined.push_str(elem.as_ref());
         }
@@ -17,6 +17,7 @@ use crossbeam_utils::atomic::AtomicCell;
 pub struct PyAsyncGen {
     inner: Coro,
     running_async: AtomicCell<bool>,
+    hooks_inited: AtomicCell<bool>,
 }
 type PyAsyncGenRef = PyRef<PyAsyncGen>;
 
@@ -36,6 +37,7 @@ impl PyAsyncGen {
         PyAsyncGen {
             inner: Coro::new(frame, name),
             running_async: AtomicCell::new(false),
+            hooks_inited: AtomicCell::new(false),
         }
     }
 
@@ -128,6 +130,31 @@ impl PyRef<PyAsyncGen> {
     }
 }
 
+/// Fire `sys.set_asyncgen_hooks`' `firstiter` callback the first time `ag` is ever resumed, as
+/// CPython's `async_gen_init_hooks` does - this has to happen here, lazily on first actual
+/// resume, rather than in `PyAsyncGen::new` above, since the hooks can be installed (or
+/// changed) by `asyncio` in between an async generator being constructed and ever being
+/// iterated, and `ag_hooks_inited` makes sure a generator that outlives a hook change still
+/// only ever sees the hooks that were in effect when it started.
+fn init_hooks(ag: &PyAsyncGenRef, vm: &VirtualMachine) -> PyResult<()> {
+    if ag.hooks_inited.swap(true) {
+        return Ok(());
+    }
+    let firstiter = crate::vm::thread::ASYNC_GEN_FIRSTITER.with(|cell| cell.borrow().clone());
+    if let Some(firstiter) = firstiter {
+        firstiter.call((ag.clone(),), vm)?;
+    }
+    Ok(())
+}
+
+// `set_asyncgen_hooks`' `finalizer` half (vm/src/stdlib/sys.rs) is stored and readable back via
+// `get_asyncgen_hooks`, but nothing here ever calls it: CPython fires it from the async
+// generator's `tp_finalize` when one gets garbage-collected without a prior `aclose()`, so
+// `asyncio` can reschedule a proper async close instead of the bytes just vanishing. Nothing in
+// this codebase hands a `PyPayload`'s drop path a `&VirtualMachine` to call back into Python
+// with, so wiring this up means giving `PyAsyncGen` real GC finalization first, not adding a
+// call here.
+
 impl Representable for PyAsyncGen {
     #[inline]
     fn repr_str(zelf: &Py<Self>, vm: &VirtualMachine) -> PyResult<String> {
@@ -219,6 +246,7 @@ impl PyAsyncGenASend {
                 }
                 self.ag.running_async.store(true);
                 self.state.store(AwaitableState::Iter);
+                init_hooks(&self.ag, vm)?;
                 if vm.is_none(&val) {
                     self.value.clone()
                 } else {
@@ -325,6 +353,7 @@ impl PyAsyncGenAThrow {
                 }
                 self.state.store(AwaitableState::Iter);
                 self.ag.running_async.store(true);
+                init_hooks(&self.ag, vm)?;
 
                 let (ty, val, tb) = self.value.clone();
                 let ret = self.ag.inner.throw(self.ag.as_object(), ty, val, tb, vm);
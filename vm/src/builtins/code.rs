@@ -333,6 +333,50 @@ impl PyCode {
         vm.ctx.new_tuple(names)
     }
 
+    /// `co_lines()`, added in CPython 3.10: yields `(start, end, line)` triples covering every
+    /// instruction, where `start`/`end` are instruction offsets (as used by `frame.f_lasti`
+    /// here, rather than the 2-bytes-per-instruction offsets of CPython's wordcode) and `line`
+    /// is `None` for instructions attributed to no source line. Consecutive instructions on the
+    /// same line are merged into one triple, same as CPython's.
+    ///
+    /// There's no `co_branches()` in any released CPython -- coverage.py's branch coverage is
+    /// derived from ordinary line events plus its own bytecode jump analysis, not a dedicated
+    /// code-object API, so there's nothing with that name to add here. Branch-accurate line
+    /// events do need per-line `sys.settrace` events while a frame runs, which don't exist yet
+    /// (see the docs on `TraceEvent` in `vm/src/protocol/callable.rs`).
+    #[pymethod]
+    fn co_lines(&self, vm: &VirtualMachine) -> PyTupleRef {
+        let mut triples = Vec::new();
+        let mut start = 0usize;
+        let mut current_line = self.code.locations.first().map(|loc| loc.row.to_usize());
+        for (idx, loc) in self.code.locations.iter().enumerate() {
+            let line = Some(loc.row.to_usize());
+            if line != current_line {
+                triples.push((start, idx, current_line));
+                start = idx;
+                current_line = line;
+            }
+        }
+        triples.push((start, self.code.locations.len(), current_line));
+
+        let triples = triples
+            .into_iter()
+            .map(|(start, end, line)| {
+                vm.ctx
+                    .new_tuple(vec![
+                        vm.ctx.new_int(start as u32).into(),
+                        vm.ctx.new_int(end as u32).into(),
+                        line.map_or_else(
+                            || vm.ctx.none(),
+                            |line| vm.ctx.new_int(line as u32).into(),
+                        ),
+                    ])
+                    .into()
+            })
+            .collect();
+        vm.ctx.new_tuple(triples)
+    }
+
     #[pymethod]
     pub fn replace(&self, args: ReplaceArgs, vm: &VirtualMachine) -> PyResult<PyCode> {
         let posonlyarg_count = match args.co_posonlyargcount {
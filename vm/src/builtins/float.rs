@@ -409,16 +409,11 @@ impl PyFloat {
             })?;
             vm.ctx.new_float(float).into()
         } else {
-            let fract = self.value.fract();
-            let value = if (fract.abs() - 0.5).abs() < f64::EPSILON {
-                if self.value.trunc() % 2.0 == 0.0 {
-                    self.value - fract
-                } else {
-                    self.value + fract
-                }
-            } else {
-                self.value.round()
-            };
+            // Share the exact (not epsilon-tolerant) tie-breaking logic with the `ndigits`
+            // branch above instead of duplicating it here.
+            let value = float_ops::round_float_digits(self.value, 0).ok_or_else(|| {
+                vm.new_overflow_error("overflow occurred during round".to_owned())
+            })?;
             let int = try_to_bigint(value, vm)?;
             vm.ctx.new_int(int).into()
         };
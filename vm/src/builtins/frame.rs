@@ -33,11 +33,6 @@ impl Representable for Frame {
 
 #[pyclass(with(Unconstructible, Py))]
 impl Frame {
-    #[pymethod]
-    fn clear(&self) {
-        // TODO
-    }
-
     #[pygetset]
     fn f_globals(&self) -> PyDictRef {
         self.globals.clone()
@@ -63,6 +58,11 @@ impl Frame {
         self.current_location().row.to_usize()
     }
 
+    #[pygetset(setter)]
+    fn set_f_lineno(&self, lineno: usize, vm: &VirtualMachine) -> PyResult<()> {
+        self.set_lineno(lineno, vm)
+    }
+
     #[pygetset]
     fn f_trace(&self) -> PyObjectRef {
         let boxed = self.trace.lock();
@@ -112,17 +112,19 @@ impl Frame {
 #[pyclass]
 impl Py<Frame> {
     #[pygetset]
-    pub fn f_back(&self, vm: &VirtualMachine) -> Option<PyRef<Frame>> {
-        // TODO: actually store f_back inside Frame struct
-
-        // get the frame in the frame stack that appears before this one.
-        // won't work if  this frame isn't in the frame stack, hence the todo above
-        vm.frames
-            .borrow()
-            .iter()
-            .rev()
-            .skip_while(|p| !p.is(self.as_object()))
-            .nth(1)
-            .cloned()
+    pub fn f_back(&self) -> Option<PyRef<Frame>> {
+        self.back.clone()
+    }
+
+    /// Release references to the frame's local variables, breaking reference cycles
+    /// (e.g. a frame that's only reachable through its own traceback) without waiting
+    /// on the cycle collector. Mirrors `frame.clear()` in CPython.
+    #[pymethod]
+    fn clear(&self, vm: &VirtualMachine) -> PyResult<()> {
+        if vm.frames.borrow().iter().any(|f| f.is(self.as_object())) {
+            return Err(vm.new_runtime_error("cannot clear an executing frame".to_owned()));
+        }
+        self.clear_locals();
+        Ok(())
     }
 }
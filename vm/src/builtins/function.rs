@@ -292,6 +292,9 @@ impl PyFunction {
         locals: Option<ArgMapping>,
         vm: &VirtualMachine,
     ) -> PyResult {
+        #[cfg(feature = "pystats")]
+        crate::stats::record_call_shape(func_args.args.len(), func_args.kwargs.len());
+
         #[cfg(feature = "jit")]
         if let Some(jitted_code) = self.jitted_code.get() {
             match jitfunc::get_jit_args(self, &func_args, jitted_code, vm) {
@@ -317,6 +320,15 @@ impl PyFunction {
         };
 
         // Construct frame:
+        //
+        // Every call through here allocates a full `PyRef<Frame>` (a `#[pyclass]`, so it's
+        // heap-allocated and GC-tracked like any other Python object) up front, whether or not
+        // anything ever inspects it via `sys._getframe`, a traceback, or `sys.settrace` - there's
+        // no lighter-weight activation record this falls back to first. Changing that means
+        // `Frame` itself splitting into a cheap non-pyclass record threaded through `with_frame`/
+        // `run_frame` (vm/mod.rs) and the generator/coroutine frame-resumption path, with the
+        // actual `PyRef<Frame>` built lazily only when one of those introspection paths asks for
+        // it - a frame-lifecycle change bigger than this call site.
         let frame = Frame::new(
             code.clone(),
             Scope::new(Some(locals), self.globals.clone()),
@@ -5,7 +5,7 @@ use crate::{
     class::PyClassImpl,
     common::{
         hash,
-        int::{bigint_to_finite_float, bytes_to_int, true_div},
+        int::{bigint_to_finite_float, bytes_to_int, num_decimal_digits, true_div},
     },
     convert::{IntoPyException, ToPyObject, ToPyResult},
     function::{
@@ -24,6 +24,11 @@ use rustpython_format::FormatSpec;
 use std::fmt;
 use std::ops::{Neg, Not};
 
+// `BigInt` here is `malachite_bigint`'s drop-in, not `num-bigint`'s - it's backed by
+// `malachite`, which already picks Karatsuba/Toom-3 for multiplication and a binary
+// exponentiation `modpow` past its own internal size thresholds, so arithmetic on large ints
+// (and the `modpow` call in `PyInt::modpow` below) gets those without anything in this file
+// needing to special-case a size or pick an algorithm itself.
 #[pyclass(module = false, name = "int")]
 #[derive(Debug)]
 pub struct PyInt {
@@ -584,6 +589,11 @@ impl PyInt {
 
     #[pymethod(magic)]
     fn format(&self, spec: PyStrRef, vm: &VirtualMachine) -> PyResult<String> {
+        // `FormatSpec` (and its `'n'` handling, shared with float/complex `__format__`) lives in
+        // the out-of-tree `rustpython-format` crate pulled in via the `rustpython-parser` git
+        // dependency, not in this repo - so making `'n'` consult `locale.localeconv()`'s
+        // `thousands_sep`/`grouping` (see `stdlib::locale`) instead of the `'d'`-equivalent
+        // grouping it falls back to today means changing that crate, not this call site.
         FormatSpec::parse(spec.as_str())
             .and_then(|format_spec| format_spec.format_int(&self.value))
             .map_err(|err| err.into_pyexception(vm))
@@ -599,6 +609,8 @@ impl PyInt {
         std::mem::size_of::<Self>() + (((self.value.bits() + 7) & !7) / 8) as usize
     }
 
+    /// `fractions.Fraction(int_instance)` and `numbers.Rational` code in general rely on this
+    /// existing on every real number type, alongside `numerator`/`denominator` below.
     #[pymethod]
     fn as_integer_ratio(&self, vm: &VirtualMachine) -> (PyRef<Self>, i32) {
         (vm.ctx.new_bigint(&self.value), 1)
@@ -740,7 +752,20 @@ impl Comparable for PyInt {
 
 impl Representable for PyInt {
     #[inline]
-    fn repr_str(zelf: &Py<Self>, _vm: &VirtualMachine) -> PyResult<String> {
+    fn repr_str(zelf: &Py<Self>, vm: &VirtualMachine) -> PyResult<String> {
+        let max_digits = vm.state.int_max_str_digits.load();
+        if max_digits != 0 {
+            // Cheap upper bound on the number of decimal digits from the bit length, checked
+            // before paying for the actual O(n^2) decimal conversion below.
+            let approx_digits =
+                (zelf.value.bits() as f64 * std::f64::consts::LOG10_2).ceil() as usize + 1;
+            if approx_digits > max_digits {
+                return Err(vm.new_value_error(format!(
+                    "Exceeds the limit ({max_digits} digits) for integer string conversion; \
+                     use sys.set_int_max_str_digits() to increase the limit"
+                )));
+            }
+        }
         Ok(zelf.value.to_string())
     }
 }
@@ -850,20 +875,45 @@ struct IntToByteArgs {
     signed: OptionalArg<ArgIntoBool>,
 }
 
+/// Enforce `sys.set_int_max_str_digits`'s limit on a base-10 literal before it reaches the
+/// quadratic-time decimal parser, since the whole point of the limit is to reject huge decimal
+/// strings before paying the cost of parsing them. Only explicit base 10 is guarded, matching
+/// CPython in the common case; other bases parse in linear time and are left alone, as is an
+/// auto-detected base of 0 (which may or may not land on a decimal literal).
+pub(crate) fn check_max_str_digits(lit: &[u8], base: u32, vm: &VirtualMachine) -> PyResult<()> {
+    if base != 10 {
+        return Ok(());
+    }
+    let max_digits = vm.state.int_max_str_digits.load();
+    if max_digits == 0 {
+        return Ok(());
+    }
+    let digits = num_decimal_digits(lit);
+    if digits > max_digits {
+        return Err(vm.new_value_error(format!(
+            "Exceeds the limit ({max_digits} digits) for integer string conversion: value has {digits} digits; use sys.set_int_max_str_digits() to increase the limit"
+        )));
+    }
+    Ok(())
+}
+
 fn try_int_radix(obj: &PyObject, base: u32, vm: &VirtualMachine) -> PyResult<BigInt> {
     debug_assert!(base == 0 || (2..=36).contains(&base));
 
     let opt = match_class!(match obj.to_owned() {
         string @ PyStr => {
             let s = string.as_str();
+            check_max_str_digits(s.as_bytes(), base, vm)?;
             bytes_to_int(s.as_bytes(), base)
         }
         bytes @ PyBytes => {
             let bytes = bytes.as_bytes();
+            check_max_str_digits(bytes, base, vm)?;
             bytes_to_int(bytes, base)
         }
         bytearray @ PyByteArray => {
             let inner = bytearray.borrow_buf();
+            check_max_str_digits(&inner, base, vm)?;
             bytes_to_int(&inner, base)
         }
         _ => {
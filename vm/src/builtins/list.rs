@@ -20,7 +20,7 @@ use crate::{
     vm::VirtualMachine,
     AsObject, Context, Py, PyObject, PyObjectRef, PyPayload, PyRef, PyResult,
 };
-use std::{fmt, ops::DerefMut};
+use std::{cell::Cell, fmt, ops::DerefMut};
 
 #[pyclass(module = false, name = "list", unhashable = true, traverse)]
 #[derive(Default)]
@@ -262,7 +262,7 @@ impl PyList {
         range: OptionalRangeArgs,
         vm: &VirtualMachine,
     ) -> PyResult<usize> {
-        let (start, stop) = range.saturate(self.len(), vm)?;
+        let (start, stop) = range.saturate(self.len());
         let index = self.mut_index_range(vm, &needle, start..stop)?;
         if let Some(index) = index.into() {
             Ok(index)
@@ -336,26 +336,13 @@ impl PyList {
     }
 }
 
+// exploits the sequence protocol's list/tuple fast path (see `PySequence::extract`) instead
+// of falling back to generic iteration when possible
 fn extract_cloned<F, R>(obj: &PyObject, mut f: F, vm: &VirtualMachine) -> PyResult<Vec<R>>
 where
     F: FnMut(PyObjectRef) -> PyResult<R>,
 {
-    use crate::builtins::PyTuple;
-    if let Some(tuple) = obj.payload_if_exact::<PyTuple>(vm) {
-        tuple.iter().map(|x| f(x.clone())).collect()
-    } else if let Some(list) = obj.payload_if_exact::<PyList>(vm) {
-        list.borrow_vec().iter().map(|x| f(x.clone())).collect()
-    } else {
-        let iter = obj.to_owned().get_iter(vm)?;
-        let iter = iter.iter::<PyObjectRef>(vm)?;
-        let len = obj.to_sequence().length_opt(vm).transpose()?.unwrap_or(0);
-        let mut v = Vec::with_capacity(len);
-        for x in iter {
-            v.push(f(x?)?);
-        }
-        v.shrink_to_fit();
-        Ok(v)
-    }
+    obj.to_sequence().extract(|x| f(x.to_owned()), vm)
 }
 
 impl MutObjectSequenceOp for PyList {
@@ -500,6 +487,39 @@ impl Representable for PyList {
     }
 }
 
+// Fast, allocation-free comparison for the common case of sorting a list of exact `int`,
+// `str`, or `float` objects: skips `rich_compare_bool`'s slot lookup and the `PyObject`/`bool`
+// round-trip for the handful of builtin types that dominate real-world sorts, mirroring
+// CPython's `unsafe_*_compare` family. Returns `None` for anything else (subclasses included,
+// since they may override comparison) so the caller can fall back to the fully general path.
+fn try_fast_compare(
+    a: &PyObjectRef,
+    b: &PyObjectRef,
+    op: PyComparisonOp,
+    vm: &VirtualMachine,
+) -> Option<bool> {
+    use crate::builtins::{PyFloat, PyInt, PyStr};
+    if let (Some(a), Some(b)) = (
+        a.payload_if_exact::<PyInt>(vm),
+        b.payload_if_exact::<PyInt>(vm),
+    ) {
+        return Some(op.eval_ord(a.as_bigint().cmp(b.as_bigint())));
+    }
+    if let (Some(a), Some(b)) = (
+        a.payload_if_exact::<PyStr>(vm),
+        b.payload_if_exact::<PyStr>(vm),
+    ) {
+        return Some(op.eval_ord(a.as_str().cmp(b.as_str())));
+    }
+    if let (Some(a), Some(b)) = (
+        a.payload_if_exact::<PyFloat>(vm),
+        b.payload_if_exact::<PyFloat>(vm),
+    ) {
+        return a.to_f64().partial_cmp(&b.to_f64()).map(|o| op.eval_ord(o));
+    }
+    None
+}
+
 fn do_sort(
     vm: &VirtualMachine,
     values: &mut Vec<PyObjectRef>,
@@ -511,7 +531,20 @@ fn do_sort(
     } else {
         PyComparisonOp::Gt
     };
-    let cmp = |a: &PyObjectRef, b: &PyObjectRef| a.rich_compare_bool(b, op, vm);
+    // Timsort can call this millions of times for a huge list; since a run of all-native
+    // comparisons never enters the bytecode eval loop (the usual place check_signals runs),
+    // Ctrl-C would otherwise have no chance to land until the whole sort finishes.
+    let compares = Cell::new(0u32);
+    let cmp = |a: &PyObjectRef, b: &PyObjectRef| {
+        compares.set(compares.get().wrapping_add(1));
+        if compares.get() % 4096 == 0 {
+            vm.check_signals()?;
+        }
+        match try_fast_compare(a, b, op, vm) {
+            Some(res) => Ok(res),
+            None => a.rich_compare_bool(b, op, vm),
+        }
+    };
 
     if let Some(ref key_func) = key_func {
         let mut items = values
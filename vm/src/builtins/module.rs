@@ -174,6 +174,13 @@ impl PyModule {
             .as_object()
             .dict()
             .ok_or_else(|| vm.new_value_error("module has no dict".to_owned()))?;
+        // PEP 562: a module-level `__dir__()` in the module's own namespace overrides the
+        // default "list the dict keys" behavior, the same way its `__getattr__` overrides
+        // attribute lookup in `getattr_inner` above.
+        if let Ok(dir) = dict.get_item(identifier!(vm, __dir__), vm) {
+            let result = dir.call((), vm)?;
+            return vm.extract_elements_with(&result, Ok);
+        }
         let attrs = dict.into_iter().map(|(k, _v)| k).collect();
         Ok(attrs)
     }
@@ -32,10 +32,15 @@ impl Constructor for PyBaseObject {
 
     fn py_new(cls: PyTypeRef, _args: Self::Args, vm: &VirtualMachine) -> PyResult {
         // more or less __new__ operator
-        let dict = if cls.is(vm.ctx.types.object_type) {
-            None
-        } else {
+        //
+        // A class that declares __slots__ (and has no base that already carries a dict - see
+        // `PyType::slot_new`) doesn't get `HAS_DICT`, so its instances store attributes only in
+        // their member-descriptor-addressed slots (`PyObject::get_slot`/`set_slot`), not in a
+        // per-instance dict on top of that.
+        let dict = if cls.slots.flags.has_feature(PyTypeFlags::HAS_DICT) {
             Some(vm.ctx.new_dict())
+        } else {
+            None
         };
 
         // Ensure that all abstract methods are implemented before instantiating instance.
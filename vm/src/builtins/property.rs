@@ -7,7 +7,8 @@ use crate::function::{IntoFuncArgs, PosArgs};
 use crate::{
     class::PyClassImpl,
     function::{FuncArgs, PySetterValue},
-    types::{Constructor, GetDescriptor, Initializer},
+    identifier,
+    types::{Callable, Constructor, GetDescriptor, Initializer, Representable},
     AsObject, Context, Py, PyObject, PyObjectRef, PyPayload, PyRef, PyResult, VirtualMachine,
 };
 
@@ -19,6 +20,10 @@ pub struct PyProperty {
     deleter: PyRwLock<Option<PyObjectRef>>,
     doc: PyRwLock<Option<PyObjectRef>>,
     name: PyRwLock<Option<PyObjectRef>>,
+    /// Whether `doc` was inherited from `fget.__doc__` rather than given explicitly, so a
+    /// later `.getter()` call knows whether to re-derive it from the new getter (matching
+    /// CPython's `property_copy`) instead of carrying the old getter's docstring along.
+    getter_doc: PyRwLock<bool>,
 }
 
 impl PyPayload for PyProperty {
@@ -59,7 +64,10 @@ impl GetDescriptor for PyProperty {
     }
 }
 
-#[pyclass(with(Constructor, Initializer, GetDescriptor), flags(BASETYPE))]
+#[pyclass(
+    with(Constructor, Initializer, GetDescriptor, Representable),
+    flags(BASETYPE)
+)]
 impl PyProperty {
     // Descriptor methods
 
@@ -126,6 +134,20 @@ impl PyProperty {
         *self.doc.write() = value;
     }
 
+    /// The name set by `__set_name__`, falling back to the getter's `__name__` like CPython's
+    /// `property_name`, for use in `repr()`.
+    fn property_name(&self, vm: &VirtualMachine) -> PyResult<Option<String>> {
+        if let Some(name) = self.name.read().clone() {
+            return Ok(Some(name.str(vm)?.as_str().to_owned()));
+        }
+        if let Some(getter) = self.getter.read().clone() {
+            if let Some(name) = vm.get_attribute_opt(getter, identifier!(vm, __name__))? {
+                return Ok(Some(name.str(vm)?.as_str().to_owned()));
+            }
+        }
+        Ok(None)
+    }
+
     #[pymethod(magic)]
     fn set_name(&self, args: PosArgs, vm: &VirtualMachine) -> PyResult<()> {
         let func_args = args.into_args(vm);
@@ -144,20 +166,46 @@ impl PyProperty {
 
     // Python builder functions
 
+    /// Rebuild through `type(self)(fget, fset, fdel, doc)` rather than constructing a plain
+    /// `PyProperty` directly, so a `property` subclass's own `__new__`/`__init__` runs again -
+    /// same as CPython's `property_copy` - and any extra state it sets on the new instance
+    /// (e.g. a `functools.cached_property`-style hybrid stashing something in its `__init__`)
+    /// survives a `getter`/`setter`/`deleter` call instead of being dropped on the floor.
+    ///
+    /// If the old doc was inherited from the old getter, let `__init__` re-derive it from the
+    /// new getter instead of carrying the stale one along; otherwise pass the explicit doc
+    /// through unchanged. `__name__` (set by `__set_name__`) isn't part of `__init__` at all -
+    /// it's copied onto the new instance directly, same as CPython does.
+    fn copy_with(
+        zelf: &PyRef<Self>,
+        getter: Option<PyObjectRef>,
+        setter: Option<PyObjectRef>,
+        deleter: Option<PyObjectRef>,
+        vm: &VirtualMachine,
+    ) -> PyResult<PyRef<Self>> {
+        let new = if *zelf.getter_doc.read() && getter.is_some() {
+            let args = (getter, setter, deleter).into_args(vm);
+            PyType::call(zelf.class(), args, vm)?
+        } else {
+            let doc = zelf.doc_getter();
+            let args = (getter, setter, deleter, doc).into_args(vm);
+            PyType::call(zelf.class(), args, vm)?
+        };
+        let new = new
+            .downcast::<Self>()
+            .map_err(|_| vm.new_type_error("expected property object".to_owned()))?;
+        *new.name.write() = zelf.name.read().clone();
+        Ok(new)
+    }
+
     #[pymethod]
     fn getter(
         zelf: PyRef<Self>,
         getter: Option<PyObjectRef>,
         vm: &VirtualMachine,
     ) -> PyResult<PyRef<Self>> {
-        PyProperty {
-            getter: PyRwLock::new(getter.or_else(|| zelf.fget())),
-            setter: PyRwLock::new(zelf.fset()),
-            deleter: PyRwLock::new(zelf.fdel()),
-            doc: PyRwLock::new(None),
-            name: PyRwLock::new(None),
-        }
-        .into_ref_with_type(vm, zelf.class().to_owned())
+        let getter = getter.or_else(|| zelf.fget());
+        Self::copy_with(&zelf, getter, zelf.fset(), zelf.fdel(), vm)
     }
 
     #[pymethod]
@@ -166,14 +214,8 @@ impl PyProperty {
         setter: Option<PyObjectRef>,
         vm: &VirtualMachine,
     ) -> PyResult<PyRef<Self>> {
-        PyProperty {
-            getter: PyRwLock::new(zelf.fget()),
-            setter: PyRwLock::new(setter.or_else(|| zelf.fset())),
-            deleter: PyRwLock::new(zelf.fdel()),
-            doc: PyRwLock::new(None),
-            name: PyRwLock::new(None),
-        }
-        .into_ref_with_type(vm, zelf.class().to_owned())
+        let setter = setter.or_else(|| zelf.fset());
+        Self::copy_with(&zelf, zelf.fget(), setter, zelf.fdel(), vm)
     }
 
     #[pymethod]
@@ -182,14 +224,8 @@ impl PyProperty {
         deleter: Option<PyObjectRef>,
         vm: &VirtualMachine,
     ) -> PyResult<PyRef<Self>> {
-        PyProperty {
-            getter: PyRwLock::new(zelf.fget()),
-            setter: PyRwLock::new(zelf.fset()),
-            deleter: PyRwLock::new(deleter.or_else(|| zelf.fdel())),
-            doc: PyRwLock::new(None),
-            name: PyRwLock::new(None),
-        }
-        .into_ref_with_type(vm, zelf.class().to_owned())
+        let deleter = deleter.or_else(|| zelf.fdel());
+        Self::copy_with(&zelf, zelf.fget(), zelf.fset(), deleter, vm)
     }
 
     #[pygetset(magic)]
@@ -229,6 +265,7 @@ impl Constructor for PyProperty {
             deleter: PyRwLock::new(None),
             doc: PyRwLock::new(None),
             name: PyRwLock::new(None),
+            getter_doc: PyRwLock::new(false),
         }
         .into_ref_with_type(vm, cls)
         .map(Into::into)
@@ -238,17 +275,38 @@ impl Constructor for PyProperty {
 impl Initializer for PyProperty {
     type Args = PropertyArgs;
 
-    fn init(zelf: PyRef<Self>, args: Self::Args, _vm: &VirtualMachine) -> PyResult<()> {
-        *zelf.getter.write() = args.fget;
+    fn init(zelf: PyRef<Self>, args: Self::Args, vm: &VirtualMachine) -> PyResult<()> {
         *zelf.setter.write() = args.fset;
         *zelf.deleter.write() = args.fdel;
-        *zelf.doc.write() = args.doc;
         *zelf.name.write() = args.name.map(|a| a.as_object().to_owned());
 
+        // If no explicit doc was given and there's a getter, inherit __doc__ from it, same as
+        // CPython's property_init; remember that it was inherited so a later getter() call
+        // knows to re-derive it from the replacement getter instead of keeping this one.
+        let getter_doc = args.doc.is_none() && args.fget.is_some();
+        let doc = if getter_doc {
+            vm.get_attribute_opt(args.fget.clone().unwrap(), identifier!(vm, __doc__))?
+        } else {
+            args.doc
+        };
+        *zelf.getter.write() = args.fget;
+        *zelf.doc.write() = doc;
+        *zelf.getter_doc.write() = getter_doc;
+
         Ok(())
     }
 }
 
+impl Representable for PyProperty {
+    #[inline]
+    fn repr_str(zelf: &Py<Self>, vm: &VirtualMachine) -> PyResult<String> {
+        Ok(match zelf.property_name(vm)? {
+            Some(name) => format!("<property object at {:#x} ({name})>", zelf.get_id()),
+            None => format!("<property object at {:#x}>", zelf.get_id()),
+        })
+    }
+}
+
 pub(crate) fn init(context: &Context) {
     PyProperty::extend_class(context, context.types.property_type);
 
@@ -41,6 +41,17 @@ impl PySet {
         PyRef::new_ref(Self::default(), ctx.types.set_type.to_owned(), None)
     }
 
+    /// Like [`PySet::new_ref`], sized up front for `capacity` elements.
+    pub fn with_capacity_ref(ctx: &Context, capacity: usize) -> PyRef<Self> {
+        PyRef::new_ref(
+            Self {
+                inner: PySetInner::with_capacity(capacity),
+            },
+            ctx.types.set_type.to_owned(),
+            None,
+        )
+    }
+
     pub fn elements(&self) -> Vec<PyObjectRef> {
         self.inner.elements()
     }
@@ -170,6 +181,15 @@ impl PySetInner {
         Ok(set)
     }
 
+    /// Like [`PySetInner::default`], sized up front for a known number of elements - used for
+    /// a set literal of known size (see `Instruction::BuildSet`), so adding its elements
+    /// doesn't resize the backing table as it goes.
+    pub(super) fn with_capacity(capacity: usize) -> Self {
+        Self {
+            content: PyRc::new(SetContentType::with_capacity(capacity)),
+        }
+    }
+
     fn fold_op<O>(
         &self,
         others: impl std::iter::Iterator<Item = O>,
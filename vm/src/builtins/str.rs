@@ -1,5 +1,5 @@
 use super::{
-    int::{PyInt, PyIntRef},
+    int::PyInt,
     iter::IterStatus::{self, Exhausted},
     PositionIterInternal, PyBytesRef, PyDict, PyTupleRef, PyType, PyTypeRef,
 };
@@ -10,7 +10,9 @@ use crate::{
     common::str::{BorrowedStr, PyStrKind, PyStrKindData},
     convert::{IntoPyException, ToPyException, ToPyObject, ToPyResult},
     format::{format, format_map},
-    function::{ArgIterable, ArgSize, FuncArgs, OptionalArg, OptionalOption, PyComparisonValue},
+    function::{
+        ArgIndex, ArgIterable, ArgSize, FuncArgs, OptionalArg, OptionalOption, PyComparisonValue,
+    },
     intern::PyInterned,
     object::{Traverse, TraverseFn},
     protocol::{PyIterReturn, PyMappingMethods, PyNumberMethods, PySequenceMethods},
@@ -413,7 +415,7 @@ impl PyStr {
 
     fn _contains(&self, needle: &PyObject, vm: &VirtualMachine) -> PyResult<bool> {
         if let Some(needle) = needle.payload::<Self>() {
-            Ok(self.as_str().contains(needle.as_str()))
+            Ok(Self::_memmem_find(self.as_str(), needle.as_str()).is_some())
         } else {
             Err(vm.new_type_error(format!(
                 "'in <string>' requires string as left operand, not {}",
@@ -947,7 +949,20 @@ impl PyStr {
                     first.as_str().to_owned()
                 }
             }
-            Err(iter) => zelf.as_str().py_join(iter)?,
+            Err(iter) => {
+                // str.join over a huge iterable never enters the bytecode eval loop (the
+                // usual place check_signals runs) when every item is a plain str, so give
+                // Ctrl-C a chance to land here too.
+                let mut count: u32 = 0;
+                let checked_iter = iter.map(|item| -> PyResult<_> {
+                    count = count.wrapping_add(1);
+                    if count % 4096 == 0 {
+                        vm.check_signals()?;
+                    }
+                    item
+                });
+                zelf.as_str().py_join(checked_iter)?
+            }
         };
         Ok(vm.ctx.new_str(joined))
     }
@@ -967,28 +982,51 @@ impl PyStr {
         self.as_str().py_find(sub.as_str(), range, find)
     }
 
+    // `memchr::memmem` does a SIMD-accelerated byte search rather than std's general-purpose
+    // `str::find`/`rfind` (Two-Way). The byte offset it returns is safe to treat as a char
+    // boundary without re-validating: a substring match of one valid-utf8 string inside
+    // another can only land on char boundaries, since a byte sequence that started mid-char
+    // could never equal the (boundary-aligned) needle's own encoding.
+    #[inline]
+    fn _memmem_find(haystack: &str, needle: &str) -> Option<usize> {
+        memchr::memmem::find(haystack.as_bytes(), needle.as_bytes())
+    }
+
+    #[inline]
+    fn _memmem_rfind(haystack: &str, needle: &str) -> Option<usize> {
+        memchr::memmem::rfind(haystack.as_bytes(), needle.as_bytes())
+    }
+
     #[pymethod]
     fn find(&self, args: FindArgs) -> isize {
-        self._find(args, |r, s| Some(Self::_to_char_idx(r, r.find(s)?)))
-            .map_or(-1, |v| v as isize)
+        self._find(args, |r, s| {
+            Some(Self::_to_char_idx(r, Self::_memmem_find(r, s)?))
+        })
+        .map_or(-1, |v| v as isize)
     }
 
     #[pymethod]
     fn rfind(&self, args: FindArgs) -> isize {
-        self._find(args, |r, s| Some(Self::_to_char_idx(r, r.rfind(s)?)))
-            .map_or(-1, |v| v as isize)
+        self._find(args, |r, s| {
+            Some(Self::_to_char_idx(r, Self::_memmem_rfind(r, s)?))
+        })
+        .map_or(-1, |v| v as isize)
     }
 
     #[pymethod]
     fn index(&self, args: FindArgs, vm: &VirtualMachine) -> PyResult<usize> {
-        self._find(args, |r, s| Some(Self::_to_char_idx(r, r.find(s)?)))
-            .ok_or_else(|| vm.new_value_error("substring not found".to_owned()))
+        self._find(args, |r, s| {
+            Some(Self::_to_char_idx(r, Self::_memmem_find(r, s)?))
+        })
+        .ok_or_else(|| vm.new_value_error("substring not found".to_owned()))
     }
 
     #[pymethod]
     fn rindex(&self, args: FindArgs, vm: &VirtualMachine) -> PyResult<usize> {
-        self._find(args, |r, s| Some(Self::_to_char_idx(r, r.rfind(s)?)))
-            .ok_or_else(|| vm.new_value_error("substring not found".to_owned()))
+        self._find(args, |r, s| {
+            Some(Self::_to_char_idx(r, Self::_memmem_rfind(r, s)?))
+        })
+        .ok_or_else(|| vm.new_value_error("substring not found".to_owned()))
     }
 
     #[pymethod]
@@ -1466,9 +1504,9 @@ pub struct FindArgs {
     #[pyarg(positional)]
     sub: PyStrRef,
     #[pyarg(positional, default)]
-    start: Option<PyIntRef>,
+    start: Option<ArgIndex>,
     #[pyarg(positional, default)]
-    end: Option<PyIntRef>,
+    end: Option<ArgIndex>,
 }
 
 impl FindArgs {
@@ -1488,6 +1526,18 @@ impl SliceableSequenceOp for PyStr {
     type Item = char;
     type Sliced = String;
 
+    // The non-ASCII branch here (and `do_slice`/`get_chars` below) is an O(n) `chars()` scan
+    // from the start of the string on every call, so a loop doing `s[i]` for increasing `i`
+    // over a large non-ASCII string is O(n^2) overall - the gap `char_len` above doesn't cover,
+    // since that field only caches the *count* of chars, not where any of them land in `bytes`.
+    // A cursor cache for this (remember the last (char_idx, byte_offset) pair and resume
+    // scanning from there) needs more care than it looks: this method takes `&self`, so the
+    // cache has to be an atomic, and a byte offset read back from a torn concurrent update
+    // could land off a char boundary - slicing `bytes` at that offset and trusting the PyStr
+    // utf8-validity invariant (see `as_str`'s `from_utf8_unchecked`) would then be UB, not just
+    // a wrong answer. Doing this safely means packing (char_idx, byte_offset) into a single
+    // atomic word so a load can't observe an inconsistent pair, which is more than a one-line
+    // fix in this function - left as a follow-up rather than retrofitting it here unverified.
     fn do_get(&self, index: usize) -> Self::Item {
         if self.is_ascii() {
             self.bytes[index] as char
@@ -1666,6 +1716,23 @@ mod tests {
             assert_eq!("TypeError", &*translated.unwrap_err().class().name(),);
         })
     }
+
+    #[test]
+    fn str_splitlines_unicode_boundaries() {
+        Interpreter::without_stdlib(Default::default()).enter(|vm| {
+            let text =
+                PyStr::from("a\nb\rc\r\nd\x0be\x0cf\x1cg\x1dh\x1ei\u{0085}j\u{2028}k\u{2029}l");
+            let lines: Vec<String> = text
+                .splitlines(anystr::SplitLinesArgs { keepends: false }, vm)
+                .into_iter()
+                .map(|o| o.downcast::<PyStr>().unwrap().as_str().to_owned())
+                .collect();
+            assert_eq!(
+                lines,
+                vec!["a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k", "l"]
+            );
+        })
+    }
 }
 
 impl AnyStrWrapper for PyStrRef {
@@ -23,13 +23,22 @@ use crate::{
     identifier,
     object::{Traverse, TraverseFn},
     protocol::{PyIterReturn, PyMappingMethods, PyNumberMethods, PySequenceMethods},
-    types::{AsNumber, Callable, GetAttr, PyTypeFlags, PyTypeSlots, Representable, SetAttr},
+    types::{
+        AsNumber, AttrKind, Callable, GetAttr, PyTypeFlags, PyTypeSlots, Representable, SetAttr,
+    },
     AsObject, Context, Py, PyObject, PyObjectRef, PyPayload, PyRef, PyResult, TryFromObject,
     VirtualMachine,
 };
 use indexmap::{map::Entry, IndexMap};
 use itertools::Itertools;
-use std::{borrow::Borrow, collections::HashSet, fmt, ops::Deref, pin::Pin, ptr::NonNull};
+use std::{
+    borrow::Borrow,
+    collections::{HashMap, HashSet},
+    fmt,
+    ops::Deref,
+    pin::Pin,
+    ptr::NonNull,
+};
 
 #[pyclass(module = false, name = "type", traverse = "manual")]
 pub struct PyType {
@@ -40,6 +49,13 @@ pub struct PyType {
     pub attributes: PyRwLock<PyAttributes>,
     pub slots: PyTypeSlots,
     pub heaptype_ext: Option<Pin<Box<HeapTypeExt>>>,
+    /// Per-attribute-name cache of whether a class attribute found via `get_attr` is a data
+    /// descriptor, a non-data descriptor, or a plain value, so instance attribute lookup
+    /// (`generic_getattr_opt`) doesn't have to walk the descriptor's own class `mro` looking for
+    /// `__set__`/`__delete__` on every access. Entries are tagged with the
+    /// `PyGlobalState::attr_cache_version` they were computed under and are ignored (and
+    /// overwritten) once that counter has moved on - see `Self::cached_attr_kind`.
+    attr_cache: PyRwLock<HashMap<&'static PyStrInterned, (u64, AttrKind)>>,
 }
 
 unsafe impl crate::object::Traverse for PyType {
@@ -231,6 +247,7 @@ impl PyType {
                 attributes: PyRwLock::new(attrs),
                 slots,
                 heaptype_ext: Some(Pin::new(Box::new(heaptype_ext))),
+                attr_cache: PyRwLock::default(),
             },
             metaclass,
             None,
@@ -276,6 +293,7 @@ impl PyType {
                 attributes: PyRwLock::new(attrs),
                 slots,
                 heaptype_ext: None,
+                attr_cache: PyRwLock::default(),
             },
             metaclass,
             None,
@@ -353,6 +371,40 @@ impl PyType {
             .find_map(|class| class.attributes.read().get(attr_name).cloned())
     }
 
+    fn classify_attr(descr: &PyObjectRef) -> AttrKind {
+        let descr_cls = descr.class();
+        let Some(descr_get) = descr_cls.mro_find_map(|cls| cls.slots.descr_get.load()) else {
+            return AttrKind::Plain;
+        };
+        if descr_cls
+            .mro_find_map(|cls| cls.slots.descr_set.load())
+            .is_some()
+        {
+            AttrKind::Data(descr_get)
+        } else {
+            AttrKind::NonData(descr_get)
+        }
+    }
+
+    /// Cached `classify_attr`, valid as long as `vm.state.attr_cache_version` hasn't moved on
+    /// since it was computed - see the `attr_cache` field doc.
+    pub(crate) fn cached_attr_kind(
+        &self,
+        attr_name: &'static PyStrInterned,
+        descr: &PyObjectRef,
+        vm: &VirtualMachine,
+    ) -> AttrKind {
+        let version = vm.state.attr_cache_version.load();
+        if let Some((cached_version, kind)) = self.attr_cache.read().get(&attr_name) {
+            if *cached_version == version {
+                return *kind;
+            }
+        }
+        let kind = Self::classify_attr(descr);
+        self.attr_cache.write().insert(attr_name, (version, kind));
+        kind
+    }
+
     // This is the internal has_attr implementation for fast lookup on a class.
     pub fn has_attr(&self, attr_name: &'static PyStrInterned) -> bool {
         self.attributes.read().contains_key(attr_name)
@@ -834,24 +886,6 @@ impl PyType {
             attributes.insert(identifier!(vm, __hash__), vm.ctx.none.clone().into());
         }
 
-        // All *classes* should have a dict. Exceptions are *instances* of
-        // classes that define __slots__ and instances of built-in classes
-        // (with exceptions, e.g function)
-        let __dict__ = identifier!(vm, __dict__);
-        attributes.entry(__dict__).or_insert_with(|| {
-            vm.ctx
-                .new_getset(
-                    "__dict__",
-                    vm.ctx.types.object_type,
-                    subtype_get_dict,
-                    subtype_set_dict,
-                )
-                .into()
-        });
-
-        // TODO: Flags is currently initialized with HAS_DICT. Should be
-        // updated when __slots__ are supported (toggling the flag off if
-        // a class has __slots__ defined).
         let heaptype_slots: Option<PyTupleTyped<PyStrRef>> =
             if let Some(x) = attributes.get(identifier!(vm, __slots__)) {
                 Some(if x.to_owned().class().is(vm.ctx.types.str_type) {
@@ -874,11 +908,39 @@ impl PyType {
                 None
             };
 
+        // All *classes* should have a dict. The exceptions are *instances* of classes that
+        // define __slots__ and whose bases don't already carry a dict of their own (any base
+        // without __slots__ has one, and so does everything inheriting from it), and instances
+        // of built-in classes (with exceptions, e.g. function).
+        let bases_have_dict = bases
+            .iter()
+            .any(|b| b.slots.flags.has_feature(PyTypeFlags::HAS_DICT));
+        let has_dict = bases_have_dict || heaptype_slots.is_none();
+
+        if has_dict {
+            let __dict__ = identifier!(vm, __dict__);
+            attributes.entry(__dict__).or_insert_with(|| {
+                vm.ctx
+                    .new_getset(
+                        "__dict__",
+                        vm.ctx.types.object_type,
+                        subtype_get_dict,
+                        subtype_set_dict,
+                    )
+                    .into()
+            });
+        }
+
         let base_member_count = base.slots.member_count;
         let member_count: usize =
             base.slots.member_count + heaptype_slots.as_ref().map(|x| x.len()).unwrap_or(0);
 
-        let flags = PyTypeFlags::heap_type_flags() | PyTypeFlags::HAS_DICT;
+        let flags = PyTypeFlags::heap_type_flags()
+            | if has_dict {
+                PyTypeFlags::HAS_DICT
+            } else {
+                PyTypeFlags::empty()
+            };
         let (slots, heaptype_ext) = {
             let slots = PyTypeSlots {
                 member_count,
@@ -945,6 +1007,10 @@ impl PyType {
         };
 
         // avoid deadlock
+        // `PyAttributes` is an `IndexMap`, so this walks the class namespace in definition
+        // order; CPython's `__set_name__` protocol is specified to fire in that same order,
+        // for every namespace entry that has the method (not just descriptors), so no extra
+        // filtering by descriptor-ness belongs here.
         let attributes = typ
             .attributes
             .read()
@@ -1172,6 +1238,10 @@ impl SetAttr for PyType {
                 )));
             }
         }
+        // This class' attribute dict just changed, which can change the data/non-data/plain
+        // classification instance lookups on it (or any subclass) cached - bump the global
+        // counter so `PyType::cached_attr_kind` recomputes everywhere instead of only here.
+        vm.state.attr_cache_version.fetch_add(1);
         if attr_name.as_str().starts_with("__") && attr_name.as_str().ends_with("__") {
             if assign {
                 zelf.update_slot::<true>(attr_name, &vm.ctx);
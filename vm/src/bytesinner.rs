@@ -7,7 +7,7 @@ use crate::{
     byte::bytes_from_object,
     cformat::cformat_bytes,
     common::hash,
-    function::{ArgIterable, Either, OptionalArg, OptionalOption, PyComparisonValue},
+    function::{ArgIndex, ArgIterable, Either, OptionalArg, OptionalOption, PyComparisonValue},
     identifier,
     literal::escape::Escape,
     protocol::PyBuffer,
@@ -154,9 +154,9 @@ pub struct ByteInnerFindOptions {
     #[pyarg(positional)]
     sub: Either<PyBytesInner, PyIntRef>,
     #[pyarg(positional, default)]
-    start: Option<PyIntRef>,
+    start: Option<ArgIndex>,
     #[pyarg(positional, default)]
-    end: Option<PyIntRef>,
+    end: Option<ArgIndex>,
 }
 
 impl ByteInnerFindOptions {
@@ -163,8 +163,14 @@ impl Coro {
             Ok(ExecutionResult::Yield(_)) => {
                 Err(vm.new_runtime_error(format!("{} ignored GeneratorExit", gen_name(gen, vm))))
             }
-            Err(e) if !is_gen_exit(&e, vm) => Err(e),
-            _ => Ok(()),
+            Err(e) if !is_gen_exit(&e, vm) => {
+                self.frame.clear_locals();
+                Err(e)
+            }
+            _ => {
+                self.frame.clear_locals();
+                Ok(())
+            }
         }
     }
 
@@ -0,0 +1,22 @@
+//! Opt-in, partial CPython C-API compatibility shim (work in progress).
+//!
+//! Enabled with the `cpython-abi` feature. The end goal is to expose enough of the CPython
+//! C API (a `PyObject*`-shaped handle, `PyArg_ParseTuple`, the basic `PyLong`/`PyUnicode`
+//! constructors and accessors, and a module-init entry point) over RustPython's own object
+//! model that simple, already-compiled CPython extension modules can be loaded and used as-is.
+//!
+//! This module currently only reserves the feature flag and the version identifier that the
+//! rest of the shim will be built against; the actual API surface (starting with a `PyObject`
+//! handle type and `Py_INCREF`/`Py_DECREF`) is not implemented yet. It depends on the loader
+//! groundwork in [`crate::extension`], which has the same limitation: RustPython's object
+//! model has no `#[repr(C)]`-stable handle type to put behind `PyObject*` today, and building
+//! one, plus the reference-counting semantics CPython extensions assume, is a large project in
+//! its own right rather than something addressable incrementally from this stub.
+//!
+//! `CPYTHON_API_VERSION` mirrors `PY_VERSION_HEX`'s role in CPython: once real entry points
+//! exist here, a loaded extension's expectations can be checked against it before any call into
+//! the extension is made.
+
+/// The (CPython-compatible) API version this shim targets, in `PY_VERSION_HEX` form
+/// (currently a placeholder; no extension should be loaded against it yet).
+pub const CPYTHON_API_VERSION: u32 = 0x0308_0000;
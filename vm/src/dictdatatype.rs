@@ -113,6 +113,28 @@ impl<T> Default for Dict<T> {
     }
 }
 
+impl<T> Dict<T> {
+    /// Like [`Dict::default`], but with `indices`/`entries` preallocated to hold `capacity`
+    /// entries without triggering a `resize` (see `DictInner::should_resize`'s 2/3 load
+    /// factor) - for building a dict/set of known size in one pass, e.g. a `{1, 2, 3}` literal
+    /// or `dict.fromkeys(...)`, without the reallocations `unchecked_push` would otherwise do
+    /// as it grows.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut indices_len = 8;
+        while capacity * 3 > indices_len * 2 {
+            indices_len <<= 1;
+        }
+        Self {
+            inner: PyRwLock::new(DictInner {
+                used: 0,
+                filled: 0,
+                indices: vec![IndexEntry::FREE; indices_len],
+                entries: Vec::with_capacity(capacity),
+            }),
+        }
+    }
+}
+
 #[derive(Clone)]
 struct DictEntry<T> {
     hash: HashValue,
@@ -1203,10 +1203,125 @@ pub(super) mod types {
     #[derive(Debug)]
     pub struct PySystemExit {}
 
-    #[pyexception(name, base = "PyBaseException", ctx = "base_exception_group", impl)]
+    #[pyexception(name, base = "PyBaseException", ctx = "base_exception_group")]
     #[derive(Debug)]
     pub struct PyBaseExceptionGroup {}
 
+    #[pyexception]
+    impl PyBaseExceptionGroup {
+        /// Build a new exception group of the same (sub)type as `self`, with the same
+        /// `message` but `excs` in place of `exceptions` - this is what `split`/`subgroup`
+        /// below call to construct their result groups, and matches CPython's default
+        /// `BaseExceptionGroup.derive` so a subclass can override it to carry extra state
+        /// through a split instead of losing it.
+        #[pymethod]
+        fn derive(
+            exc: PyBaseExceptionRef,
+            excs: PyObjectRef,
+            vm: &VirtualMachine,
+        ) -> PyResult<PyBaseExceptionRef> {
+            let message = exc.get_arg(0).unwrap_or_else(|| vm.ctx.new_str("").into());
+            vm.invoke(exc.class(), (message, excs))?
+                .downcast()
+                .map_err(|_| {
+                    vm.new_type_error("derive() did not return an exception group".to_owned())
+                })
+        }
+
+        #[pymethod]
+        fn subgroup(
+            exc: PyBaseExceptionRef,
+            condition: PyObjectRef,
+            vm: &VirtualMachine,
+        ) -> PyResult<Option<PyBaseExceptionRef>> {
+            Ok(exceptiongroup_split(&exc, &condition, false, vm)?.0)
+        }
+
+        #[pymethod]
+        fn split(
+            exc: PyBaseExceptionRef,
+            condition: PyObjectRef,
+            vm: &VirtualMachine,
+        ) -> PyResult<(Option<PyBaseExceptionRef>, Option<PyBaseExceptionRef>)> {
+            exceptiongroup_split(&exc, &condition, true, vm)
+        }
+    }
+
+    /// Whether `sub_exc` matches a `split`/`subgroup` `condition` - an exception type, a tuple
+    /// of exception types (checked the same way an `except` clause would), or an arbitrary
+    /// predicate called with the sub-exception.
+    fn exceptiongroup_condition_matches(
+        sub_exc: &PyObjectRef,
+        condition: &PyObjectRef,
+        vm: &VirtualMachine,
+    ) -> PyResult<bool> {
+        let is_type_or_tuple = condition.class().is(vm.ctx.types.type_type)
+            || condition.try_to_ref::<PyTuple>(vm).is_ok();
+        if is_type_or_tuple {
+            sub_exc.is_instance(condition, vm)
+        } else {
+            condition.call((sub_exc.clone(),), vm)?.try_to_bool(vm)
+        }
+    }
+
+    /// The shared recursion behind `BaseExceptionGroup.split`/`.subgroup` (CPython's
+    /// `exceptiongroup_split_recursive`): walks `exc`'s nested exception-group tree, sorting
+    /// every leaf exception into a `matched` tree and (when `construct_rest` is set, i.e. this
+    /// is a `split` rather than a `subgroup`) a `rest` tree, preserving the original nesting by
+    /// calling the (possibly subclass-overridden) `derive` method at each group level rather
+    /// than rebuilding groups directly. Matched leaves keep their original identity instead of
+    /// being rewrapped, matching `except*`'s requirement that a caught leaf exception is the
+    /// exact object it was raised as.
+    fn exceptiongroup_split(
+        exc: &PyBaseExceptionRef,
+        condition: &PyObjectRef,
+        construct_rest: bool,
+        vm: &VirtualMachine,
+    ) -> PyResult<(Option<PyBaseExceptionRef>, Option<PyBaseExceptionRef>)> {
+        if !exc.fast_isinstance(vm.ctx.exceptions.base_exception_group) {
+            return Ok(
+                if exceptiongroup_condition_matches(exc.as_object(), condition, vm)? {
+                    (Some(exc.clone()), None)
+                } else if construct_rest {
+                    (None, Some(exc.clone()))
+                } else {
+                    (None, None)
+                },
+            );
+        }
+
+        let sub_excs: PyTupleRef = exc
+            .get_arg(1)
+            .and_then(|o| o.downcast().ok())
+            .ok_or_else(|| vm.new_type_error("exception group has no exceptions".to_owned()))?;
+
+        let mut matched = Vec::new();
+        let mut rest = Vec::new();
+        for sub in sub_excs.iter() {
+            let sub_exc: PyBaseExceptionRef = sub.clone().downcast().map_err(|_| {
+                vm.new_type_error("exception group contains a non-exception".to_owned())
+            })?;
+            let (sub_matched, sub_rest) =
+                exceptiongroup_split(&sub_exc, condition, construct_rest, vm)?;
+            matched.extend(sub_matched.map(PyObjectRef::from));
+            rest.extend(sub_rest.map(PyObjectRef::from));
+        }
+
+        let derive = |excs: Vec<PyObjectRef>| -> PyResult<Option<PyBaseExceptionRef>> {
+            if excs.is_empty() {
+                return Ok(None);
+            }
+            let group = vm.call_method(exc.as_object(), "derive", (vm.ctx.new_tuple(excs),))?;
+            group.downcast().map(Some).map_err(|_| {
+                vm.new_type_error("derive() did not return an exception group".to_owned())
+            })
+        };
+
+        let matched = derive(matched)?;
+        let rest = if construct_rest { derive(rest)? } else { None };
+        Ok((matched, rest))
+    }
+
     #[pyexception(name, base = "PyBaseExceptionGroup", ctx = "exception_group", impl)]
     #[derive(Debug)]
     pub struct PyExceptionGroup {}
@@ -0,0 +1,27 @@
+//! Native extension module ABI (work in progress).
+//!
+//! The goal is to let a Rust crate be compiled separately as a `cdylib` and loaded by
+//! RustPython's import system as a native module at runtime, analogous to a CPython extension
+//! module (`.pyd`/`.so`). That's really two pieces:
+//!
+//! 1. A stable entry point and version negotiation so a loader can tell whether an extension
+//!    was built against a compatible version of this crate before calling into it.
+//! 2. A loader that opens the file (`dlopen`/`LoadLibraryW`) and resolves that entry point.
+//!
+//! This module only defines (1). (2) is deliberately not implemented here: a real loader needs
+//! either an external dynamic-loading dependency (e.g. `libloading`) or raw `libc`/`windows-sys`
+//! FFI, and RustPython's object model doesn't yet have the opaque, `#[repr(C)]`-safe handle
+//! types that a cross-crate-version-stable vtable would need to expose in place of
+//! [`PyObjectRef`] (which has no ABI stability guarantee across compiler/crate versions). Adding
+//! those handle types is a prerequisite for a real vtable and is its own, larger project.
+//!
+//! For now, extensions are expected to be compiled *into* the same binary as the interpreter
+//! (the same way the `stdlib` crate is) and registered with
+//! [`VirtualMachine::add_native_module`][crate::vm::VirtualMachine::add_native_module] during
+//! startup; this module exists so that code written against it today keeps working once a
+//! dlopen-based loader lands on top of it.
+
+/// Bumped whenever a change to this module's contract could break an extension built against an
+/// older version of this crate. A future loader must refuse to load an extension whose
+/// `ABI_VERSION` doesn't match this one.
+pub const ABI_VERSION: u32 = 1;
@@ -108,6 +108,14 @@ fn format_internal(
                 };
 
                 // FIXME: compiler can intern specs using parser tree. Then this call can be interned_str
+                //
+                // f-strings, str.format(), and format() already converge on the single
+                // `vm.format`/`__format__` dispatch point below (see `Instruction::FormatValue`'s
+                // handler in frame.rs for the f-string side) and `FormatSpec`'s own parsing
+                // (`rustpython_format`, an out-of-tree crate this workspace doesn't vendor) is
+                // where a parsed-spec cache would have to live - caching the *string* produced
+                // here instead wouldn't avoid the reparse, since every caller still hands
+                // `vm.format` a fresh `format_spec: &str` to parse into a `FormatSpec`.
                 pystr = vm.format(&argument, vm.ctx.new_str(format_spec))?;
                 pystr.as_ref()
             }
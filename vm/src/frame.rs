@@ -96,6 +96,13 @@ type Lasti = std::cell::Cell<u32>;
 pub struct Frame {
     pub code: PyRef<PyCode>,
 
+    /// The frame that was on top of `vm.frames` when this one was created, captured once at
+    /// construction time (mirroring CPython's `f_back = tstate->frame` in `_PyFrame_New_NoTrack`)
+    /// rather than re-derived by searching the live call stack. That means it keeps working after
+    /// this frame (or its caller) has since been popped, e.g. when a debugger or test framework
+    /// inspects a traceback well after the exception that created it has propagated out.
+    pub back: Option<FrameRef>,
+
     pub fastlocals: PyMutex<Box<[Option<PyObjectRef>]>>,
     pub(crate) cells_frees: Box<[PyCellRef]>,
     pub locals: ArgMapping,
@@ -151,6 +158,7 @@ impl Frame {
         };
 
         Frame {
+            back: vm.frames.borrow().last().cloned(),
             fastlocals: PyMutex::new(vec![None; code.varnames.len()].into_boxed_slice()),
             cells_frees,
             locals: scope.locals,
@@ -180,6 +188,43 @@ impl Frame {
         }
     }
 
+    /// Implements `frame.f_lineno = lineno`, the jump debuggers like `pdb` use to move
+    /// execution to a different line within the same frame. More conservative than
+    /// CPython's `frame_setlineno`: any active block (loop/try/with) on the frame refuses
+    /// the jump outright, rather than only jumps that would cross into or out of one,
+    /// since nothing here yet tracks which instructions are "inside" a block versus just
+    /// at the same nesting depth as the target line.
+    pub fn set_lineno(&self, lineno: usize, vm: &VirtualMachine) -> PyResult<()> {
+        let target = self
+            .code
+            .locations
+            .iter()
+            .position(|loc| loc.row.to_usize() == lineno)
+            .ok_or_else(|| {
+                vm.new_value_error(format!("line {lineno} does not exist in this code object"))
+            })?;
+        let mut state = self.state.lock();
+        if !state.blocks.is_empty() {
+            return Err(vm.new_value_error(
+                "can't jump into or out of a 'try', 'with', 'for' or 'while' block".to_owned(),
+            ));
+        }
+        // `FrameState::lasti` (under `feature = "threading"`) is the copy the running
+        // dispatch loop actually reads from; `self.lasti` only mirrors it for lock-free
+        // reads from outside the loop (see `ExecutingFrame::lasti`/`update_lasti`). Both
+        // need to move, or the jump wouldn't affect execution once it resumes.
+        #[cfg(feature = "threading")]
+        {
+            state.lasti = target as u32;
+            self.lasti.store(target as u32, atomic::Ordering::Relaxed);
+        }
+        #[cfg(not(feature = "threading"))]
+        {
+            self.lasti.set(target as u32);
+        }
+        Ok(())
+    }
+
     pub fn locals(&self, vm: &VirtualMachine) -> PyResult<ArgMapping> {
         let locals = &self.locals;
         let code = &**self.code;
@@ -217,6 +262,18 @@ impl Frame {
         }
         Ok(locals.clone())
     }
+
+    /// Release references held by fast locals, cell and free variables. Used by
+    /// `frame.clear()` and by a closed generator/coroutine to let its locals be
+    /// reclaimed immediately rather than waiting for the frame itself to be dropped.
+    pub(crate) fn clear_locals(&self) {
+        for local in self.fastlocals.lock().iter_mut() {
+            *local = None;
+        }
+        for cell in self.cells_frees.iter() {
+            cell.set(None);
+        }
+    }
 }
 
 impl Py<Frame> {
@@ -276,9 +333,9 @@ impl Py<Frame> {
         filename.as_str().contains("importlib") && filename.as_str().contains("_bootstrap")
     }
 
-    pub fn next_external_frame(&self, vm: &VirtualMachine) -> Option<FrameRef> {
-        self.f_back(vm).map(|mut back| loop {
-            back = if let Some(back) = back.to_owned().f_back(vm) {
+    pub fn next_external_frame(&self) -> Option<FrameRef> {
+        self.f_back().map(|mut back| loop {
+            back = if let Some(back) = back.to_owned().f_back() {
                 back
             } else {
                 break back;
@@ -346,6 +403,12 @@ impl ExecutingFrame<'_> {
 
     fn run(&mut self, vm: &VirtualMachine) -> PyResult<ExecutionResult> {
         flame_guard!(format!("Frame::run({})", self.code.obj_name));
+        // This is a plain `match`-based dispatch loop rather than a computed-goto or
+        // tail-call chain: `become` isn't available on stable Rust, and superinstructions
+        // (e.g. fusing LOAD_FAST+LOAD_FAST or COMPARE_OP+JUMP) would need matching changes
+        // to the bytecode format in `rustpython_compiler_core` and to codegen, not just
+        // here. LLVM already turns this `match` into a jump table for the common case, so
+        // that's the dispatch strategy until there's a concrete, measured case for more.
         // Execute until return or exception:
         let instrs = &self.code.instructions;
         let mut arg_state = bytecode::OpArgState::default();
@@ -490,6 +553,9 @@ impl ExecutingFrame<'_> {
     ) -> FrameResult {
         vm.check_signals()?;
 
+        #[cfg(feature = "pystats")]
+        crate::stats::record_opcode(instruction);
+
         flame_guard!(format!(
             "Frame::execute_instruction({})",
             instruction.display(arg, &self.code.code).to_string()
@@ -714,9 +780,10 @@ impl ExecutingFrame<'_> {
                 Ok(None)
             }
             bytecode::Instruction::BuildSet { size } => {
-                let set = PySet::new_ref(&vm.ctx);
+                let size = size.get(arg) as usize;
+                let set = PySet::with_capacity_ref(&vm.ctx, size);
                 {
-                    for element in self.pop_multiple(size.get(arg) as usize) {
+                    for element in self.pop_multiple(size) {
                         set.add(element, vm)?;
                     }
                 }
@@ -1202,10 +1269,31 @@ impl ExecutingFrame<'_> {
                 self.push_value(type_alias.into_ref(&vm.ctx).into());
                 Ok(None)
             }
+            bytecode::Instruction::TypeVarTuple => {
+                let type_name = self.pop_value();
+                let type_var_tuple: PyObjectRef = _typing::make_typevartuple(type_name)
+                    .into_ref(&vm.ctx)
+                    .into();
+                self.push_value(type_var_tuple);
+                Ok(None)
+            }
+            bytecode::Instruction::TypeParamSpec => {
+                let type_name = self.pop_value();
+                let param_spec: PyObjectRef =
+                    _typing::make_paramspec(type_name).into_ref(&vm.ctx).into();
+                self.push_value(param_spec);
+                Ok(None)
+            }
         }
     }
 
     #[inline]
+    // Adaptive inline caches (keyed by type/dict version tags, invalidated from
+    // `__setattr__` on classes and module dicts) would let `LoadAttr`/`LoadMethod`/
+    // `LoadGlobal` skip this lookup on repeat hits, but there's no cache slot to put
+    // them in: `bytecode::CodeUnit` is a fixed-size `{op, arg}` pair with no per-site
+    // storage, so this would mean a bytecode format change in `rustpython_compiler_core`
+    // plus a version counter on `PyType`/`PyDict`, not just a change here.
     fn load_global_or_builtin(&self, name: &Py<PyStr>, vm: &VirtualMachine) -> PyResult {
         self.globals
             .get_chain(self.builtins, name, vm)?
@@ -1455,10 +1543,24 @@ impl ExecutingFrame<'_> {
 
     fn collect_ex_args(&mut self, vm: &VirtualMachine, has_kwargs: bool) -> PyResult<FuncArgs> {
         let kwargs = if has_kwargs {
-            let kw_dict: PyDictRef = self.pop_value().downcast().map_err(|_| {
-                // TODO: check collections.abc.Mapping
-                vm.new_type_error("Kwargs must be a dict.".to_owned())
-            })?;
+            let kw_obj = self.pop_value();
+            let kw_dict = match kw_obj.downcast::<PyDict>() {
+                Ok(kw_dict) => kw_dict,
+                Err(kw_obj) => {
+                    if vm
+                        .get_method(kw_obj.clone(), identifier!(vm, keys))
+                        .is_none()
+                    {
+                        return Err(vm.new_type_error(format!(
+                            "argument after ** must be a mapping, not {}",
+                            kw_obj.class()
+                        )));
+                    }
+                    let kw_dict = vm.ctx.new_dict();
+                    kw_dict.merge_object(kw_obj, vm)?;
+                    kw_dict
+                }
+            };
             let mut kwargs = IndexMap::new();
             for (key, value) in kw_dict.into_iter() {
                 let key = key
@@ -1996,6 +2098,16 @@ impl ExecutingFrame<'_> {
         Ok(None)
     }
 
+    // This `blocks` stack (pushed by `SetupExcept`/`SetupFinally`, unwound by `unwind_blocks`
+    // below) is CPython's pre-3.11 model: entering a `try` always costs a push/pop here even
+    // when no exception is ever raised, unlike an exception-table design where the happy path
+    // just runs straight through the `try` body's instructions with no block bookkeeping at
+    // all. Moving to that model isn't a change to this function - it's removing `self.blocks`
+    // and this mechanism entirely, replacing `SetupExcept`/`SetupFinally`/`PopBlock` with a
+    // (start, end, handler, stack_depth) side table on `CodeObject` that codegen builds instead
+    // of emitting those instructions, and rewriting `unwind_blocks`'s reason-dispatch to look up
+    // the table by instruction offset on raise. That's a coordinated change across `bytecode.rs`,
+    // the codegen crate, and this file's unwinding logic, not a local optimization here.
     fn push_block(&mut self, typ: BlockType) {
         // eprintln!("block pushed: {:.60?} {}", typ, self.state.stack.len());
         self.state.blocks.push(Block {
@@ -58,6 +58,16 @@ into_func_args_from_tuple!((v1, T1), (v2, T2), (v3, T3), (v4, T4), (v5, T5));
 /// The `FuncArgs` struct is one of the most used structs then creating
 /// a rust function that can be called from python. It holds both positional
 /// arguments, as well as keyword arguments passed to the function.
+///
+/// Every call allocates one of these (a `Vec` plus an `IndexMap`) before `FromArgs`
+/// binds it to the callee's actual parameters, which is the allocation a vectorcall-style
+/// convention (pass a borrowed argument slice straight from the caller's stack, no
+/// intermediate struct) would remove. Doing that for real needs a second calling
+/// convention alongside this one - a new `Callable`-ish entry point, codegen in
+/// `#[pyfunction]`/`#[pymethod]` in `derive-impl` to implement it, and every existing
+/// caller (the bytecode eval loop's `CallFunction*` handling, `PyObject::call`, builtin
+/// methods calling other callables) choosing between them - not something to retrofit
+/// onto `FuncArgs` in place.
 #[derive(Debug, Default, Clone, Traverse)]
 pub struct FuncArgs {
     pub args: Vec<PyObjectRef>,
@@ -236,6 +246,9 @@ pub enum ArgumentError {
     TooManyArgs,
     /// The function doesn't accept a keyword argument with the given name.
     InvalidKeywordArgument(String),
+    /// The function requires a positional (or positional-or-keyword) argument with the
+    /// given name, but one wasn't provided.
+    RequiredPositionalArgument(String),
     /// The function require a keyword argument with the given name, but one wasn't provided
     RequiredKeywordArgument(String),
     /// An exception was raised while binding arguments to the function
@@ -268,10 +281,13 @@ impl ArgumentError {
                 num_given
             )),
             ArgumentError::InvalidKeywordArgument(name) => {
-                vm.new_type_error(format!("{name} is an invalid keyword argument"))
+                vm.new_type_error(format!("got an unexpected keyword argument '{name}'"))
+            }
+            ArgumentError::RequiredPositionalArgument(name) => {
+                vm.new_type_error(format!("missing required argument: '{name}'"))
             }
             ArgumentError::RequiredKeywordArgument(name) => {
-                vm.new_type_error(format!("Required keyqord only argument {name}"))
+                vm.new_type_error(format!("missing required keyword-only argument: '{name}'"))
             }
             ArgumentError::Exception(ex) => ex,
         }
@@ -9,6 +9,12 @@ use std::{
     ops::Deref,
 };
 
+/// The global (per-interpreter) table backing `sys.intern` and the compiler's automatic
+/// interning of identifiers (`PyCode::make_name`, via `Context::intern_str`). Because every
+/// name produced by compiling source code and every explicit `sys.intern` call round-trips
+/// through here, two equal identifiers almost always end up as the same `PyStrInterned`
+/// pointer - which is what lets `DictKey for Py<PyStr>::key_is` (see `dictdatatype.rs`) short
+/// circuit a dict probe with a pointer comparison before falling back to content equality.
 #[derive(Debug)]
 pub struct StringPool {
     inner: PyRwLock<std::collections::HashSet<CachedPyStrRef, ahash::RandomState>>,
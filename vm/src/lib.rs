@@ -49,10 +49,13 @@ mod codecs;
 pub mod compiler;
 pub mod convert;
 mod coroutine;
+#[cfg(feature = "cpython-abi")]
+pub mod cpython_abi;
 mod dictdatatype;
 #[cfg(feature = "rustpython-compiler")]
 pub mod eval;
 pub mod exceptions;
+pub mod extension;
 pub mod format;
 pub mod frame;
 pub mod function;
@@ -67,12 +70,16 @@ pub mod protocol;
 pub mod py_io;
 #[cfg(feature = "serde")]
 pub mod py_serde;
+pub mod pyfuture;
 pub mod readline;
 pub mod recursion;
 pub mod scope;
 pub mod sequence;
 pub mod signal;
 pub mod sliceable;
+mod stack_probe;
+#[cfg(feature = "pystats")]
+pub mod stats;
 pub mod stdlib;
 pub mod suggestion;
 pub mod types;
@@ -80,6 +87,8 @@ pub mod utils;
 pub mod version;
 pub mod vm;
 pub mod warn;
+#[cfg(all(target_arch = "wasm32", target_os = "wasi"))]
+pub mod wasi_preview2;
 #[cfg(windows)]
 pub mod windows;
 
@@ -89,7 +98,7 @@ pub use self::object::{
     AsObject, Py, PyAtomicRef, PyExact, PyObject, PyObjectRef, PyPayload, PyRef, PyRefExact,
     PyResult, PyWeakRef,
 };
-pub use self::vm::{Context, Interpreter, Settings, VirtualMachine};
+pub use self::vm::{ClassBuilder, Context, Interpreter, Settings, VirtualMachine};
 
 pub use rustpython_common as common;
 pub use rustpython_compiler_core::{bytecode, frozen};
@@ -399,6 +399,16 @@ impl Py<PyWeak> {
     }
 }
 
+/// An instance's `__dict__`. This is a plain, full `PyDictRef` - the same combined
+/// index+entries `Dict` (see `dictdatatype.rs`) that a Python-level `{}` literal gets, not
+/// CPython's split representation, where instances of the same class share one keys table
+/// (invalidated via a type version tag when the class changes) and each instance stores only
+/// a values array. Getting that sharing right means a second `Dict` representation, a place on
+/// `PyType` to own the shared keys table, and version-tag invalidation plumbed through
+/// `PyType::__setattr__`/`__delattr__` and every site that currently assumes an instance's
+/// `__dict__` is a self-contained, independently mutable `PyDictRef` (e.g. `vars()`,
+/// `obj.__dict__ = {...}` reassignment via `set_dict` below) - not something this struct can
+/// grow into in place.
 #[derive(Debug)]
 pub(super) struct InstanceDict {
     pub(super) d: PyRwLock<PyDictRef>,
@@ -459,6 +469,12 @@ impl<T: PyObjectPayload> PyInner<T> {
 /// this reference counting is accounted for by this type. Use the `.clone()`
 /// method to create a new reference and increment the amount of references
 /// to the python object by 1.
+/// `PyObjectRef` is a plain boxed pointer, not a tagged union - every `int`/`float`,
+/// cached or not (see `Context::INT_CACHE_POOL_RANGE` and `new_int`/`new_float`), is a
+/// real heap allocation behind this pointer. A tagged-pointer or NaN-boxed representation
+/// that inlines small payloads would need `PyObject`'s `Erased`/`PyInner` layout, every
+/// `AsObject`/`PyPayload` downcast, and the GC/refcounting in this module all rebuilt
+/// around "maybe not a pointer" - not a change this type can absorb on its own.
 #[repr(transparent)]
 pub struct PyObjectRef {
     ptr: NonNull<PyObject>,
@@ -2,13 +2,15 @@
 //! https://docs.python.org/3/c-api/buffer.html
 
 use crate::{
+    builtins::PyMemoryView,
     common::{
         borrow::{BorrowedValue, BorrowedValueMut},
         lock::{MapImmutable, PyMutex, PyMutexGuard},
     },
+    identifier,
     object::PyObjectPayload,
     sliceable::SequenceIndexOp,
-    types::Unconstructible,
+    types::{AsBuffer, Unconstructible},
     Py, PyObject, PyObjectRef, PyPayload, PyRef, PyResult, TryFromBorrowedObject, VirtualMachine,
 };
 use itertools::Itertools;
@@ -145,6 +147,20 @@ impl<'a> TryFromBorrowedObject<'a> for PyBuffer {
         if let Some(f) = as_buffer {
             return f(obj, vm);
         }
+        // PEP 688: a class without the native buffer slot may still expose the buffer
+        // protocol from Python by defining `__buffer__`, which is expected to return a
+        // `memoryview` onto the data; borrow that memoryview's buffer in that case.
+        if let Some(buffer_getter) = vm.get_special_method(obj, identifier!(vm, __buffer__))? {
+            let flags = 0; // TODO: PyBUF_* flags parameter
+            let view = buffer_getter.invoke((flags,), vm)?;
+            let view = view.downcast::<PyMemoryView>().map_err(|view| {
+                vm.new_type_error(format!(
+                    "__buffer__ returned non-memoryview object from {}",
+                    view.class().name()
+                ))
+            })?;
+            return AsBuffer::as_buffer(&view, vm);
+        }
         Err(vm.new_type_error(format!(
             "a bytes-like object is required, not '{}'",
             cls.name()
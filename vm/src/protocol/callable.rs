@@ -48,6 +48,15 @@ impl<'a> PyCallable<'a> {
     pub fn invoke(&self, args: impl IntoFuncArgs, vm: &VirtualMachine) -> PyResult {
         let args = args.into_args(vm);
         vm.trace_event(TraceEvent::Call)?;
+        // `vm.with_recursion`/`check_recursive_call` already bounds every call that ends up
+        // executing bytecode (`VirtualMachine::with_frame`) and a handful of native re-entry
+        // points (comparisons, repr, `issubclass`/`isinstance`, see `protocol/object.rs`). A
+        // call chain made entirely of native `#[pyfunction]`/`#[pymethod]` calls that never runs
+        // bytecode - e.g. mutually recursive `__getattr__`s - isn't counted here: adding a check
+        // at this single generic entry point would double-count every ordinary Python function
+        // call (which already increments depth once in `with_frame`), silently halving the
+        // effective default recursion limit for normal code. Doing this without that regression
+        // needs depth-counting moved to be exactly-once across both paths, not layered on top.
         let result = (self.call)(self.obj, args, vm);
         vm.trace_event(TraceEvent::Return)?;
         result
@@ -55,6 +64,17 @@ impl<'a> PyCallable<'a> {
 }
 
 /// Trace events for sys.settrace and sys.setprofile.
+///
+/// This only covers what CPython calls `call`/`return`, and fires them around *every* call
+/// (native functions included) with whatever frame is on top of `vm.frames` at the time --
+/// for a call into a Python function, that's still the *caller's* frame, since the callee's
+/// frame isn't pushed until inside `(self.call)(...)` below. `bdb` (and therefore `pdb`)
+/// needs two things this doesn't provide yet: `call` events carrying the callee's own frame
+/// (CPython fires it from the top of `PyEval_EvalFrameDefault`, i.e. `VirtualMachine::with_frame`
+/// here, not from the generic call wrapper), and per-line `line` events while a frame runs,
+/// which would also need to start honoring `Frame::trace`/`Frame::trace_lines` (currently
+/// stored on every frame but never read) as the local trace function CPython's trace protocol
+/// returns from a `call` event and then calls instead of the global one.
 enum TraceEvent {
     Call,
     Return,
@@ -45,6 +45,7 @@ impl PyObject {
     pub fn try_int(&self, vm: &VirtualMachine) -> PyResult<PyIntRef> {
         fn try_convert(obj: &PyObject, lit: &[u8], vm: &VirtualMachine) -> PyResult<PyIntRef> {
             let base = 10;
+            int::check_max_str_digits(lit, base, vm)?;
             let i = bytes_to_int(lit, base).ok_or_else(|| {
                 let repr = match obj.repr(vm) {
                     Ok(repr) => repr,
@@ -13,7 +13,7 @@ use crate::{
     function::{Either, OptionalArg, PyArithmeticValue, PySetterValue},
     object::PyPayload,
     protocol::{PyIter, PyMapping, PySequence},
-    types::{Constructor, PyComparisonOp},
+    types::{AttrKind, Constructor, PyComparisonOp},
     AsObject, Py, PyObject, PyObjectRef, PyResult, TryFromObject, VirtualMachine,
 };
 
@@ -228,23 +228,18 @@ impl PyObject {
         let name = name_str.as_str();
         let obj_cls = self.class();
         let cls_attr_name = vm.ctx.interned_str(name_str);
-        let cls_attr = match cls_attr_name.and_then(|name| obj_cls.get_attr(name)) {
-            Some(descr) => {
-                let descr_cls = descr.class();
-                let descr_get = descr_cls.mro_find_map(|cls| cls.slots.descr_get.load());
-                if let Some(descr_get) = descr_get {
-                    if descr_cls
-                        .mro_find_map(|cls| cls.slots.descr_set.load())
-                        .is_some()
-                    {
+        let cls_attr =
+            match cls_attr_name.and_then(|name| obj_cls.get_attr(name).map(|d| (name, d))) {
+                Some((attr_name, descr)) => match obj_cls.cached_attr_kind(attr_name, &descr, vm) {
+                    AttrKind::Data(descr_get) => {
                         let cls = obj_cls.to_owned().into();
                         return descr_get(descr, Some(self.to_owned()), Some(cls), vm).map(Some);
                     }
-                }
-                Some((descr, descr_get))
-            }
-            None => None,
-        };
+                    AttrKind::NonData(descr_get) => Some((descr, Some(descr_get))),
+                    AttrKind::Plain => Some((descr, None)),
+                },
+                None => None,
+            };
 
         let dict = dict.or_else(|| self.dict());
 
@@ -0,0 +1,86 @@
+//! Bridging between Python awaitables and Rust's [`std::future::Future`], for embedders
+//! that want to drive Python coroutines from a Rust async executor (e.g. tokio).
+//!
+//! This does not give Python real asynchronous I/O; it drives the coroutine's
+//! send/throw protocol on each poll via [`thread::with_current_vm`], so the calling
+//! executor must already be running inside [`Interpreter::enter`](crate::Interpreter::enter).
+//! A coroutine that never actually completes synchronous work between `await`
+//! points will busy-poll rather than truly suspend.
+
+use crate::{convert::ToPyObject, protocol::PyIterReturn, vm::thread, PyObjectRef, PyResult};
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, RawWaker, RawWakerVTable, Waker},
+};
+
+/// Wraps a Python awaitable so it can be polled as a Rust [`Future`].
+///
+/// The output is the value the Python coroutine returned (via `StopIteration.value`),
+/// or the exception it raised.
+pub struct PyAwaitableFuture {
+    coro: PyObjectRef,
+}
+
+impl PyAwaitableFuture {
+    /// Calls `__await__` on `awaitable` and wraps the resulting iterator.
+    pub fn new(awaitable: PyObjectRef) -> PyResult<Self> {
+        thread::with_current_vm(|vm| {
+            let coro = awaitable.get_iter(vm)?.into();
+            Ok(Self { coro })
+        })
+    }
+}
+
+impl Future for PyAwaitableFuture {
+    type Output = PyResult<PyObjectRef>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        thread::with_current_vm(|vm| {
+            match PyIterReturn::from_pyresult(
+                vm.call_method(&self.coro, "send", (vm.ctx.none(),)),
+                vm,
+            ) {
+                Ok(PyIterReturn::Return(_)) => {
+                    // nothing actually suspends execution here, so just re-poll
+                    cx.waker().wake_by_ref();
+                    Poll::Pending
+                }
+                Ok(PyIterReturn::StopIteration(value)) => {
+                    Poll::Ready(Ok(value.unwrap_or_else(|| vm.ctx.none())))
+                }
+                Err(e) => Poll::Ready(Err(e)),
+            }
+        })
+    }
+}
+
+// A waker that does nothing on wake, used to drive a future to completion without a
+// real reactor; every poll that returns `Pending` here means "call me again right away".
+fn noop_waker() -> Waker {
+    const VTABLE: RawWakerVTable = RawWakerVTable::new(
+        |_| RawWaker::new(std::ptr::null(), &VTABLE),
+        |_| {},
+        |_| {},
+        |_| {},
+    );
+    unsafe { Waker::from_raw(RawWaker::new(std::ptr::null(), &VTABLE)) }
+}
+
+/// Spawns a Rust future so its result can be handed to Python, for embedders that bridge
+/// a handful of host operations into synchronous VM calls. There is no real reactor, so
+/// this busy-polls `future` on the current thread until it resolves.
+pub fn block_on<F, T>(mut future: F, vm: &crate::VirtualMachine) -> PyResult
+where
+    F: Future<Output = PyResult<T>> + Unpin,
+    T: ToPyObject,
+{
+    let waker = noop_waker();
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        match Pin::new(&mut future).poll(&mut cx) {
+            Poll::Ready(result) => return result.map(|v| v.to_pyobject(vm)),
+            Poll::Pending => continue,
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use crate::{
-    builtins::PyIntRef, function::OptionalArg, sliceable::SequenceIndexOp, types::PyComparisonOp,
-    vm::VirtualMachine, AsObject, PyObject, PyObjectRef, PyResult,
+    function::{ArgIndex, OptionalArg},
+    sliceable::SequenceIndexOp,
+    types::PyComparisonOp,
+    vm::VirtualMachine,
+    AsObject, PyObject, PyObjectRef, PyResult,
 };
 use optional::Optioned;
 use std::ops::{Deref, Range};
@@ -138,19 +141,24 @@ impl<T: Clone> SequenceMutExt<T> for Vec<T> {
 #[derive(FromArgs)]
 pub struct OptionalRangeArgs {
     #[pyarg(positional, optional)]
-    start: OptionalArg<PyObjectRef>,
+    start: OptionalArg<ArgIndex>,
     #[pyarg(positional, optional)]
-    stop: OptionalArg<PyObjectRef>,
+    stop: OptionalArg<ArgIndex>,
 }
 
 impl OptionalRangeArgs {
-    pub fn saturate(self, len: usize, vm: &VirtualMachine) -> PyResult<(usize, usize)> {
-        let saturate = |obj: PyObjectRef| -> PyResult<_> {
-            obj.try_into_value(vm)
-                .map(|int: PyIntRef| int.as_bigint().saturated_at(len))
-        };
-        let start = self.start.map_or(Ok(0), saturate)?;
-        let stop = self.stop.map_or(Ok(len), saturate)?;
-        Ok((start, stop))
+    pub fn saturate(self, len: usize) -> (usize, usize) {
+        let range = saturate_range(self.start.into_option(), self.stop.into_option(), len);
+        (range.start, range.end)
     }
 }
+
+/// Saturate an optional `__index__`-convertible start/stop bound to a valid range over a
+/// sequence of the given length, the way `list.index`, `str.find`, and friends all do:
+/// `None` means the respective default (`0` for start, `len` for stop), and out-of-range
+/// magnitudes (including ones too large for an `isize`) saturate instead of erroring.
+pub fn saturate_range(start: Option<ArgIndex>, stop: Option<ArgIndex>, len: usize) -> Range<usize> {
+    let start = start.map_or(0, |i| i.as_bigint().saturated_at(len));
+    let stop = stop.map_or(len, |i| i.as_bigint().saturated_at(len).min(len));
+    start..stop
+}
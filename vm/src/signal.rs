@@ -1,10 +1,12 @@
 #![cfg_attr(target_os = "wasi", allow(dead_code))]
-use crate::{PyResult, VirtualMachine};
+use crate::{common::lock::PyMutex, PyResult, VirtualMachine};
 use std::{
+    cmp::Ordering as CmpOrdering,
+    collections::BinaryHeap,
     fmt,
     sync::{
         atomic::{AtomicBool, Ordering},
-        mpsc,
+        mpsc, Arc,
     },
 };
 
@@ -123,3 +125,120 @@ pub fn user_signal_channel() -> (UserSignalSender, UserSignalReceiver) {
     let (tx, rx) = mpsc::channel();
     (UserSignalSender { tx }, UserSignalReceiver { rx })
 }
+
+/// A [`UserSignalSender`]-like handle backed by a bounded channel, so a misbehaving or
+/// fast producer thread can't queue an unbounded number of signals for the VM thread to
+/// process; once the channel is full, [`BoundedUserSignalSender::send`] reports back
+/// rather than growing forever.
+#[derive(Clone, Debug)]
+pub struct BoundedUserSignalSender {
+    tx: mpsc::SyncSender<UserSignal>,
+}
+
+impl BoundedUserSignalSender {
+    pub fn send(&self, sig: UserSignal) -> Result<(), UserSignalSendError> {
+        self.tx
+            .try_send(sig)
+            .map_err(|e| match e {
+                mpsc::TrySendError::Full(sig) | mpsc::TrySendError::Disconnected(sig) => sig,
+            })
+            .map_err(UserSignalSendError)?;
+        set_triggered();
+        Ok(())
+    }
+}
+
+/// Like [`user_signal_channel`], but the returned sender refuses to queue more than
+/// `bound` pending signals, so a producer thread can observe backpressure instead of
+/// the VM thread falling behind indefinitely.
+pub fn user_signal_channel_bounded(bound: usize) -> (BoundedUserSignalSender, UserSignalReceiver) {
+    let (tx, rx) = mpsc::sync_channel(bound);
+    (BoundedUserSignalSender { tx }, UserSignalReceiver { rx })
+}
+
+struct PrioritizedSignal {
+    priority: u8,
+    sig: UserSignal,
+}
+
+impl PartialEq for PrioritizedSignal {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+impl Eq for PrioritizedSignal {}
+impl PartialOrd for PrioritizedSignal {
+    fn partial_cmp(&self, other: &Self) -> Option<CmpOrdering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for PrioritizedSignal {
+    fn cmp(&self, other: &Self) -> CmpOrdering {
+        self.priority.cmp(&other.priority)
+    }
+}
+
+/// The sending half of a [`priority_user_signal_channel`]: higher `priority` values are
+/// delivered to the VM thread first, regardless of send order.
+#[derive(Clone)]
+pub struct PriorityUserSignalSender {
+    queue: Arc<PyMutex<BinaryHeap<PrioritizedSignal>>>,
+    bound: usize,
+}
+
+impl PriorityUserSignalSender {
+    pub fn send(&self, priority: u8, sig: UserSignal) -> Result<(), UserSignalSendError> {
+        let mut queue = self.queue.lock();
+        if queue.len() >= self.bound {
+            return Err(UserSignalSendError(sig));
+        }
+        queue.push(PrioritizedSignal { priority, sig });
+        drop(queue);
+        set_triggered();
+        Ok(())
+    }
+}
+
+/// The receiving half of a [`priority_user_signal_channel`].
+pub struct PriorityUserSignalReceiver {
+    queue: Arc<PyMutex<BinaryHeap<PrioritizedSignal>>>,
+}
+
+impl PriorityUserSignalReceiver {
+    /// Drains every pending signal, highest priority first.
+    pub(crate) fn drain(&self) -> Vec<UserSignal> {
+        let mut queue = self.queue.lock();
+        let mut out = Vec::with_capacity(queue.len());
+        while let Some(p) = queue.pop() {
+            out.push(p.sig);
+        }
+        out
+    }
+}
+
+/// A bounded, priority-ordered alternative to [`user_signal_channel`] for embedders that
+/// need other threads to schedule work on the VM thread (a cross-thread interrupt) where
+/// some interrupts (e.g. a forced shutdown) must jump ahead of routine ones.
+pub fn priority_user_signal_channel(
+    bound: usize,
+) -> (PriorityUserSignalSender, PriorityUserSignalReceiver) {
+    let queue = Arc::new(PyMutex::new(BinaryHeap::new()));
+    (
+        PriorityUserSignalSender {
+            queue: queue.clone(),
+            bound,
+        },
+        PriorityUserSignalReceiver { queue },
+    )
+}
+
+/// Runs every pending signal queued on `rx`, highest priority first. Embedders that use
+/// [`priority_user_signal_channel`] should call this at a point they control (e.g. their
+/// own `presite_hook` or a periodic callback) since it isn't wired into the normal
+/// [`check_signals`] pump used by `vm.signal_rx`.
+pub fn pump_priority_signals(vm: &VirtualMachine, rx: &PriorityUserSignalReceiver) -> PyResult<()> {
+    for sig in rx.drain() {
+        sig(vm)?;
+    }
+    Ok(())
+}
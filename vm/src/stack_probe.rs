@@ -0,0 +1,75 @@
+//! A best-effort probe of how much native (Rust) stack space is left on the
+//! current thread. [`crate::vm::VirtualMachine::check_recursive_call`] uses this so that
+//! raising `sys.recursionlimit` can't turn runaway Python recursion into a real stack
+//! overflow: the probe catches it with a `RecursionError` first, regardless of what
+//! the configured limit is.
+//!
+//! The platform call that finds a thread's stack bounds is only made once per thread
+//! (cached in a thread-local); every subsequent check is just pointer arithmetic against
+//! the address of a local variable.
+
+use std::cell::Cell;
+
+#[derive(Copy, Clone)]
+struct StackBounds {
+    /// The lowest addressable byte of this thread's stack (the stack grows down to it).
+    low: usize,
+}
+
+thread_local! {
+    static STACK_BOUNDS: Cell<Option<StackBounds>> = Cell::new(None);
+}
+
+#[cfg(target_os = "linux")]
+fn probe_bounds() -> Option<StackBounds> {
+    use std::mem::MaybeUninit;
+    unsafe {
+        let mut attr = MaybeUninit::<libc::pthread_attr_t>::zeroed();
+        if libc::pthread_getattr_np(libc::pthread_self(), attr.as_mut_ptr()) != 0 {
+            return None;
+        }
+        let mut attr = attr.assume_init();
+        let mut stackaddr: *mut libc::c_void = std::ptr::null_mut();
+        let mut stacksize: libc::size_t = 0;
+        let got_stack = libc::pthread_attr_getstack(&attr, &mut stackaddr, &mut stacksize) == 0;
+        libc::pthread_attr_destroy(&mut attr);
+        got_stack.then(|| StackBounds {
+            low: stackaddr as usize,
+        })
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn probe_bounds() -> Option<StackBounds> {
+    unsafe {
+        let this = libc::pthread_self();
+        // Unlike glibc's attr API, these return the *high* end of the stack (its
+        // starting point, since it grows down) together with its size.
+        let stackaddr = libc::pthread_get_stackaddr_np(this) as usize;
+        let stacksize = libc::pthread_get_stacksize_np(this) as usize;
+        Some(StackBounds {
+            low: stackaddr.saturating_sub(stacksize),
+        })
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn probe_bounds() -> Option<StackBounds> {
+    None
+}
+
+/// Returns the number of bytes left before this thread runs off the end of its native
+/// stack, or `None` if that can't be determined on this platform.
+pub(crate) fn remaining_bytes() -> Option<usize> {
+    let bounds = STACK_BOUNDS.with(|cell| {
+        if let Some(bounds) = cell.get() {
+            return Some(bounds);
+        }
+        let bounds = probe_bounds();
+        cell.set(bounds);
+        bounds
+    })?;
+    let probe = 0u8;
+    let current_sp = &probe as *const u8 as usize;
+    Some(current_sp.saturating_sub(bounds.low))
+}
@@ -0,0 +1,89 @@
+//! Opt-in interpreter statistics collection (`pystats` feature), loosely modeled on CPython's
+//! `Python/specialize.c` `--enable-pystats` build: count how often each opcode and each call-site
+//! argument shape is seen while running, and dump a machine-readable report so further work on
+//! the dispatch loop can be justified with data instead of guesswork.
+//!
+//! This does not (yet) report inline-cache hit rates, because there is no inline-cache
+//! infrastructure to report on: `Frame::load_global_or_builtin`'s doc comment (`vm/src/frame.rs`)
+//! already notes that adding one needs a `bytecode::CodeUnit` format change to give call sites
+//! somewhere to store a cache slot, which is a separate, larger change than this module.
+use crate::bytecode::Instruction;
+use once_cell::sync::Lazy;
+use std::{
+    collections::HashMap,
+    sync::atomic::{AtomicU64, Ordering},
+    sync::Mutex,
+};
+
+static OPCODE_COUNTS: Lazy<Vec<AtomicU64>> = Lazy::new(|| {
+    std::iter::repeat_with(|| AtomicU64::new(0))
+        .take(256)
+        .collect()
+});
+
+/// Keyed by `(positional_args, keyword_args)`, saturating at `u8::MAX` each, which is plenty to
+/// tell "a handful" from "this call site takes way more args than usual" apart.
+static CALL_SHAPE_COUNTS: Lazy<Mutex<HashMap<(u8, u8), u64>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+#[inline]
+fn opcode_name(instruction: Instruction) -> String {
+    // `Instruction`'s fields are all zero-sized `Arg<T>` markers (the actual operand lives in the
+    // separately-passed `OpArg`), so `{:?}` on a bare `Instruction` is just the variant name,
+    // optionally followed by an empty `{ .. }` when it has fields.
+    let debug = format!("{instruction:?}");
+    debug.split([' ', '{']).next().unwrap_or(&debug).to_owned()
+}
+
+pub fn record_opcode(instruction: Instruction) {
+    let idx: u8 = instruction.into();
+    OPCODE_COUNTS[idx as usize].fetch_add(1, Ordering::Relaxed);
+}
+
+pub fn record_call_shape(num_positional: usize, num_keyword: usize) {
+    let key = (
+        num_positional.min(u8::MAX as usize) as u8,
+        num_keyword.min(u8::MAX as usize) as u8,
+    );
+    *CALL_SHAPE_COUNTS.lock().unwrap().entry(key).or_insert(0) += 1;
+}
+
+/// Render the counters collected so far as a small, hand-rolled JSON report (no `serde_json`
+/// dependency needed for a handful of flat fields).
+pub fn dump_report() -> String {
+    let mut opcodes: Vec<(String, u64)> = OPCODE_COUNTS
+        .iter()
+        .enumerate()
+        .map(|(idx, count)| {
+            let count = count.load(Ordering::Relaxed);
+            let name = Instruction::try_from(idx as u8)
+                .map_or_else(|_| "<unknown>".to_owned(), opcode_name);
+            (name, count)
+        })
+        .filter(|(_, count)| *count > 0)
+        .collect();
+    opcodes.sort_unstable_by(|a, b| b.1.cmp(&a.1));
+
+    let mut call_shapes: Vec<((u8, u8), u64)> = CALL_SHAPE_COUNTS
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|(&shape, &count)| (shape, count))
+        .collect();
+    call_shapes.sort_unstable_by(|a, b| b.1.cmp(&a.1));
+
+    let opcodes_json = opcodes
+        .iter()
+        .map(|(name, count)| format!("{{\"op\":\"{name}\",\"count\":{count}}}"))
+        .collect::<Vec<_>>()
+        .join(",");
+    let call_shapes_json = call_shapes
+        .iter()
+        .map(|((positional, keyword), count)| {
+            format!("{{\"positional\":{positional},\"keyword\":{keyword},\"count\":{count}}}")
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+
+    format!("{{\"opcodes\":[{opcodes_json}],\"call_shapes\":[{call_shapes_json}]}}")
+}
@@ -0,0 +1,246 @@
+pub(crate) use _abc::make_module;
+
+#[pymodule]
+mod _abc {
+    use crate::{
+        builtins::{PyFrozenSet, PyStrRef, PyType, PyTypeRef, PyWeak},
+        class::PyClassImpl,
+        common::lock::PyMutex,
+        AsObject, PyObject, PyObjectRef, PyPayload, PyRef, PyResult, TryFromObject, VirtualMachine,
+    };
+    use crossbeam_utils::atomic::AtomicCell;
+    use std::fmt;
+
+    /// The data `_abc_init` attaches to every ABC as `cls._abc_impl`: the registry of virtual
+    /// subclasses and the positive/negative subclass-check caches, mirroring what CPython's own
+    /// `_abc` C module hangs off the same attribute. Membership is tracked via weakrefs, same as
+    /// `Lib/_py_abc.py`'s `WeakSet`-backed fallback, so registering/checking a class here doesn't
+    /// keep it alive forever.
+    #[pyattr]
+    #[pyclass(module = "_abc", name = "_abc_data")]
+    #[derive(PyPayload)]
+    struct AbcImpl {
+        registry: PyMutex<Vec<PyRef<PyWeak>>>,
+        cache: PyMutex<Vec<PyRef<PyWeak>>>,
+        negative_cache: PyMutex<Vec<PyRef<PyWeak>>>,
+        negative_cache_version: AtomicCell<u64>,
+    }
+
+    impl fmt::Debug for AbcImpl {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("_abc_data")
+        }
+    }
+
+    #[pyclass]
+    impl AbcImpl {}
+
+    fn weak_contains(list: &[PyRef<PyWeak>], obj: &PyObject) -> bool {
+        list.iter().any(|w| w.upgrade().is_some_and(|o| o.is(obj)))
+    }
+
+    /// Drop dead entries, then add `obj` if it isn't already (weakly) present.
+    fn weak_add(list: &mut Vec<PyRef<PyWeak>>, obj: &PyObject, vm: &VirtualMachine) {
+        list.retain(|w| w.upgrade().is_some());
+        if !weak_contains(list, obj) {
+            if let Ok(weak) = obj.to_owned().downgrade(None, vm) {
+                list.push(weak);
+            }
+        }
+    }
+
+    fn get_impl(cls: &PyTypeRef, vm: &VirtualMachine) -> PyResult<PyRef<AbcImpl>> {
+        cls.as_object()
+            .get_attr("_abc_impl", vm)?
+            .downcast()
+            .map_err(|_| vm.new_type_error("_abc_impl attribute is in an invalid state".to_owned()))
+    }
+
+    #[pyfunction]
+    fn get_cache_token(vm: &VirtualMachine) -> u64 {
+        vm.state.abc_invalidation_counter.load()
+    }
+
+    #[pyfunction]
+    fn _abc_init(cls: PyTypeRef, vm: &VirtualMachine) -> PyResult<()> {
+        let mut abstracts = Vec::new();
+        for (name, value) in cls.attributes.read().iter() {
+            if vm
+                .get_attribute_opt(value.clone(), "__isabstractmethod__")?
+                .is_some_and(|v| v.is_true(vm).unwrap_or(false))
+            {
+                abstracts.push(name.to_object());
+            }
+        }
+        for base in cls.bases.read().iter() {
+            let Ok(base_abstracts) = base.as_object().get_attr("__abstractmethods__", vm) else {
+                continue;
+            };
+            let names =
+                vm.extract_elements_with(&base_abstracts, |o| PyStrRef::try_from_object(vm, o))?;
+            for name in names {
+                let is_abstract = vm
+                    .get_attribute_opt(cls.clone().into(), &name)?
+                    .is_some_and(|v| {
+                        vm.get_attribute_opt(v, "__isabstractmethod__")
+                            .ok()
+                            .flatten()
+                            .is_some_and(|v| v.is_true(vm).unwrap_or(false))
+                    });
+                if is_abstract {
+                    abstracts.push(name.into());
+                }
+            }
+        }
+        let abstracts = PyFrozenSet::from_iter(vm, abstracts)?.into_ref(&vm.ctx);
+        cls.as_object()
+            .set_attr("__abstractmethods__", abstracts.into(), vm)?;
+
+        let data = AbcImpl {
+            registry: PyMutex::default(),
+            cache: PyMutex::default(),
+            negative_cache: PyMutex::default(),
+            negative_cache_version: AtomicCell::new(vm.state.abc_invalidation_counter.load()),
+        }
+        .into_ref(&vm.ctx);
+        cls.as_object().set_attr("_abc_impl", data, vm)?;
+        Ok(())
+    }
+
+    #[pyfunction]
+    fn _abc_register(
+        cls: PyTypeRef,
+        subclass: PyTypeRef,
+        vm: &VirtualMachine,
+    ) -> PyResult<PyTypeRef> {
+        if subclass.as_object().is_subclass(cls.as_object(), vm)? {
+            return Ok(subclass); // Already a subclass.
+        }
+        if cls.as_object().is_subclass(subclass.as_object(), vm)? {
+            // This would create a cycle, which is bad for the algorithm below.
+            return Err(vm.new_runtime_error("Refusing to create an inheritance cycle".to_owned()));
+        }
+        let data = get_impl(&cls, vm)?;
+        weak_add(&mut data.registry.lock(), subclass.as_object(), vm);
+        vm.state.abc_invalidation_counter.fetch_add(1);
+        Ok(subclass)
+    }
+
+    #[pyfunction]
+    fn _abc_instancecheck(
+        cls: PyTypeRef,
+        instance: PyObjectRef,
+        vm: &VirtualMachine,
+    ) -> PyResult<bool> {
+        let subclass = instance.class().to_owned();
+        if weak_contains(&get_impl(&cls, vm)?.cache.lock(), subclass.as_object()) {
+            return Ok(true);
+        }
+        let subtype = vm
+            .get_attribute_opt(instance, "__class__")?
+            .and_then(|c| c.downcast::<PyType>().ok())
+            .unwrap_or_else(|| subclass.clone());
+        if subtype.is(&subclass) {
+            let data = get_impl(&cls, vm)?;
+            if data.negative_cache_version.load() == vm.state.abc_invalidation_counter.load()
+                && weak_contains(&data.negative_cache.lock(), subclass.as_object())
+            {
+                return Ok(false);
+            }
+            return _abc_subclasscheck(cls, subclass, vm);
+        }
+        Ok(_abc_subclasscheck(cls.clone(), subclass, vm)? || _abc_subclasscheck(cls, subtype, vm)?)
+    }
+
+    #[pyfunction]
+    fn _abc_subclasscheck(
+        cls: PyTypeRef,
+        subclass: PyTypeRef,
+        vm: &VirtualMachine,
+    ) -> PyResult<bool> {
+        let data = get_impl(&cls, vm)?;
+
+        if weak_contains(&data.cache.lock(), subclass.as_object()) {
+            return Ok(true);
+        }
+
+        let current_version = vm.state.abc_invalidation_counter.load();
+        if data.negative_cache_version.load() < current_version {
+            data.negative_cache.lock().clear();
+            data.negative_cache_version.store(current_version);
+        } else if weak_contains(&data.negative_cache.lock(), subclass.as_object()) {
+            return Ok(false);
+        }
+
+        let ok = vm.call_method(cls.as_object(), "__subclasshook__", (subclass.clone(),))?;
+        if !ok.is(&vm.ctx.not_implemented()) {
+            let ok = ok.try_to_bool(vm)?;
+            if ok {
+                weak_add(&mut data.cache.lock(), subclass.as_object(), vm);
+            } else {
+                weak_add(&mut data.negative_cache.lock(), subclass.as_object(), vm);
+            }
+            return Ok(ok);
+        }
+
+        // Check if it's a direct subclass.
+        if subclass.fast_issubclass(&cls) {
+            weak_add(&mut data.cache.lock(), subclass.as_object(), vm);
+            return Ok(true);
+        }
+
+        // Check if it's a subclass of a registered class (recursive).
+        for rcls in data.registry.lock().iter().filter_map(|w| w.upgrade()) {
+            if subclass.as_object().is_subclass(&rcls, vm)? {
+                weak_add(&mut data.cache.lock(), subclass.as_object(), vm);
+                return Ok(true);
+            }
+        }
+
+        // Check if it's a subclass of a subclass (recursive).
+        let subtypes = cls.subclasses();
+        for scls in subtypes.borrow_vec().iter() {
+            if let Ok(scls) = PyTypeRef::try_from_object(vm, scls.clone()) {
+                if subclass.as_object().is_subclass(scls.as_object(), vm)? {
+                    weak_add(&mut data.cache.lock(), subclass.as_object(), vm);
+                    return Ok(true);
+                }
+            }
+        }
+
+        // No dice; update the negative cache.
+        weak_add(&mut data.negative_cache.lock(), subclass.as_object(), vm);
+        Ok(false)
+    }
+
+    #[pyfunction]
+    fn _reset_registry(cls: PyTypeRef, vm: &VirtualMachine) -> PyResult<()> {
+        get_impl(&cls, vm)?.registry.lock().clear();
+        Ok(())
+    }
+
+    #[pyfunction]
+    fn _reset_caches(cls: PyTypeRef, vm: &VirtualMachine) -> PyResult<()> {
+        let data = get_impl(&cls, vm)?;
+        data.cache.lock().clear();
+        data.negative_cache.lock().clear();
+        Ok(())
+    }
+
+    #[pyfunction]
+    fn _get_dump(
+        cls: PyTypeRef,
+        vm: &VirtualMachine,
+    ) -> PyResult<(Vec<PyObjectRef>, Vec<PyObjectRef>, Vec<PyObjectRef>, u64)> {
+        let data = get_impl(&cls, vm)?;
+        fn live(list: &[PyRef<PyWeak>]) -> Vec<PyObjectRef> {
+            list.iter().filter_map(|w| w.upgrade()).collect()
+        }
+        Ok((
+            live(&data.registry.lock()),
+            live(&data.cache.lock()),
+            live(&data.negative_cache.lock()),
+            data.negative_cache_version.load(),
+        ))
+    }
+}
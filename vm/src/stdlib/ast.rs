@@ -26,7 +26,7 @@ use rustpython_parser as parser;
 #[pymodule]
 mod _ast {
     use crate::{
-        builtins::{PyStrRef, PyTupleRef},
+        builtins::{PyDictRef, PyStrRef, PyTupleRef, PyTypeRef},
         function::FuncArgs,
         AsObject, Context, PyObjectRef, PyPayload, PyResult, VirtualMachine,
     };
@@ -69,6 +69,22 @@ mod _ast {
             Ok(())
         }
 
+        /// AST nodes have no `__init__` fields that aren't also visible through `__dict__`
+        /// (`init` above writes them as attributes), so, like CPython's `ast_type_reduce`,
+        /// pickling just needs to reconstruct an empty node of the same type and refill its
+        /// `__dict__` -- enabling tools that cache parsed/transformed trees across processes.
+        #[pymethod(magic)]
+        fn reduce(
+            zelf: PyObjectRef,
+            vm: &VirtualMachine,
+        ) -> (PyTypeRef, PyTupleRef, Option<PyDictRef>) {
+            (
+                zelf.class().to_owned(),
+                vm.ctx.empty_tuple.clone(),
+                zelf.dict(),
+            )
+        }
+
         #[pyattr(name = "_fields")]
         fn fields(ctx: &Context) -> PyTupleRef {
             ctx.empty_tuple.clone()
@@ -68,7 +68,7 @@ mod builtins {
     }
 
     #[pyfunction]
-    fn bin(x: PyIntRef) -> String {
+    fn bin(x: ArgIndex) -> String {
         let x = x.as_bigint();
         if x.is_negative() {
             format!("-0b{:b}", x.abs())
@@ -83,9 +83,9 @@ mod builtins {
     }
 
     #[pyfunction]
-    fn chr(i: PyIntRef, vm: &VirtualMachine) -> PyResult<String> {
+    fn chr(i: ArgIndex, vm: &VirtualMachine) -> PyResult<String> {
         let value = i
-            .try_to_primitive::<isize>(vm)?
+            .as_bigint()
             .to_u32()
             .and_then(char::from_u32)
             .ok_or_else(|| vm.new_value_error("chr() arg not in range(0x110000)".to_owned()))?;
@@ -11,7 +11,7 @@ mod _collections {
         common::lock::{PyMutex, PyRwLock, PyRwLockReadGuard, PyRwLockWriteGuard},
         function::{KwArgs, OptionalArg, PyComparisonValue},
         iter::PyExactSizeIterator,
-        protocol::{PyIterReturn, PySequenceMethods},
+        protocol::{PyBuffer, PyIterReturn, PySequenceMethods},
         recursion::ReprGuard,
         sequence::{MutObjectSequenceOp, OptionalRangeArgs},
         sliceable::SequenceIndexOp,
@@ -20,7 +20,8 @@ mod _collections {
             Iterable, PyComparisonOp, Representable, SelfIter,
         },
         utils::collection_repr,
-        AsObject, Py, PyObject, PyObjectRef, PyPayload, PyRef, PyResult, VirtualMachine,
+        AsObject, Py, PyObject, PyObjectRef, PyPayload, PyRef, PyResult, TryFromBorrowedObject,
+        VirtualMachine,
     };
     use crossbeam_utils::atomic::AtomicCell;
     use std::cmp::max;
@@ -53,6 +54,24 @@ mod _collections {
         fn borrow_deque_mut(&self) -> PyRwLockWriteGuard<'_, VecDeque<PyObjectRef>> {
             self.deque.write()
         }
+
+        /// Like `iter.try_to_value::<Vec<PyObjectRef>>(vm)`, but for a byte-sized
+        /// buffer-protocol object (bytes, bytearray, memoryview of bytes, ...) it reads the
+        /// whole buffer at once and builds the `PyInt`s directly, instead of going through
+        /// the general iterator protocol one `__next__` call per element.
+        fn elements_from_iterable(
+            iter: &PyObject,
+            vm: &VirtualMachine,
+        ) -> PyResult<Vec<PyObjectRef>> {
+            if let Ok(buffer) = PyBuffer::try_from_borrowed_object(vm, iter) {
+                if buffer.desc.itemsize == 1 {
+                    if let Some(bytes) = buffer.as_contiguous() {
+                        return Ok(bytes.iter().map(|&b| vm.ctx.new_int(b).into()).collect());
+                    }
+                }
+            }
+            iter.try_to_value(vm)
+        }
     }
 
     #[pyclass(
@@ -123,7 +142,7 @@ mod _collections {
         fn _extend(&self, iter: &PyObject, vm: &VirtualMachine) -> PyResult<()> {
             self.state.fetch_add(1);
             let max_len = self.maxlen;
-            let mut elements: Vec<PyObjectRef> = iter.try_to_value(vm)?;
+            let mut elements: Vec<PyObjectRef> = Self::elements_from_iterable(iter, vm)?;
             if let Some(max_len) = max_len {
                 if max_len > elements.len() {
                     let mut deque = self.borrow_deque_mut();
@@ -170,7 +189,7 @@ mod _collections {
         ) -> PyResult<usize> {
             let start_state = self.state.load();
 
-            let (start, stop) = range.saturate(self.len(), vm)?;
+            let (start, stop) = range.saturate(self.len());
             let index = self.mut_index_range(vm, &needle, start..stop)?;
             if start_state != self.state.load() {
                 Err(vm.new_runtime_error("deque mutated during iteration".to_owned()))
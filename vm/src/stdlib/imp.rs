@@ -156,10 +156,10 @@ mod _imp {
 
     #[pyfunction]
     fn _frozen_module_names(vm: &VirtualMachine) -> PyResult<Vec<PyObjectRef>> {
-        let names = vm
-            .state
-            .frozen
-            .keys()
+        let mut names: Vec<_> = vm.state.frozen.keys().collect();
+        names.sort();
+        let names = names
+            .into_iter()
             .map(|&name| vm.ctx.new_str(name).into())
             .collect();
         Ok(names)
@@ -3716,7 +3716,7 @@ mod _io {
 #[cfg(any(not(target_arch = "wasm32"), target_os = "wasi"))]
 #[pymodule]
 mod fileio {
-    use super::{Offset, _io::*};
+    use super::{_io::*, Offset};
     use crate::{
         builtins::{PyBaseExceptionRef, PyStr, PyStrRef},
         common::crt_fd::Fd,
@@ -5,15 +5,17 @@ mod decl {
     use crate::stdlib::itertools::decl::int::get_value;
     use crate::{
         builtins::{
-            int, tuple::IntoPyTuple, PyGenericAlias, PyInt, PyIntRef, PyList, PyTuple, PyTupleRef,
-            PyTypeRef,
+            int, tuple::IntoPyTuple, PyGenericAlias, PyList, PyTuple, PyTupleRef, PyTypeRef,
         },
         common::{
             lock::{PyMutex, PyRwLock, PyRwLockWriteGuard},
             rc::PyRc,
         },
         convert::ToPyObject,
-        function::{ArgCallable, ArgIntoBool, FuncArgs, OptionalArg, OptionalOption, PosArgs},
+        function::{
+            ArgCallable, ArgIndex, ArgIntoBool, ArgSize, FuncArgs, OptionalArg, OptionalOption,
+            PosArgs,
+        },
         identifier,
         protocol::{PyIter, PyIterReturn, PyNumber},
         stdlib::sys,
@@ -364,7 +366,7 @@ mod decl {
     struct PyRepeatNewArgs {
         object: PyObjectRef,
         #[pyarg(any, optional)]
-        times: OptionalArg<PyIntRef>,
+        times: OptionalArg<ArgSize>,
     }
 
     impl Constructor for PyItertoolsRepeat {
@@ -376,8 +378,8 @@ mod decl {
             vm: &VirtualMachine,
         ) -> PyResult {
             let times = match times.into_option() {
-                Some(int) => {
-                    let val: isize = int.try_to_primitive(vm)?;
+                Some(val) => {
+                    let val: isize = val.into();
                     // times always >= 0.
                     Some(PyRwLock::new(val.to_usize().unwrap_or(0)))
                 }
@@ -1475,7 +1477,7 @@ mod decl {
         #[pyarg(any)]
         iterable: PyObjectRef,
         #[pyarg(any)]
-        r: PyIntRef,
+        r: ArgIndex,
     }
 
     impl Constructor for PyItertoolsCombinations {
@@ -1705,7 +1707,7 @@ mod decl {
         #[pyarg(positional)]
         iterable: PyObjectRef,
         #[pyarg(positional, optional)]
-        r: OptionalOption<PyObjectRef>,
+        r: OptionalOption<ArgIndex>,
     }
 
     impl Constructor for PyItertoolsPermutations {
@@ -1723,10 +1725,7 @@ mod decl {
             // If None, it behaves the same as if it was not provided.
             let r = match r.flatten() {
                 Some(r) => {
-                    let val = r
-                        .payload::<PyInt>()
-                        .ok_or_else(|| vm.new_type_error("Expected int as r".to_owned()))?
-                        .as_bigint();
+                    let val = r.as_bigint();
 
                     if val.is_negative() {
                         return Err(vm.new_value_error("r must be non-negative".to_owned()));
@@ -1970,7 +1969,7 @@ mod decl {
         #[pyarg(positional)]
         iterable_ref: PyObjectRef,
         #[pyarg(positional)]
-        n: PyIntRef,
+        n: ArgIndex,
     }
 
     impl Constructor for PyItertoolsBatched {
@@ -1,3 +1,4 @@
+mod abc;
 #[cfg(feature = "rustpython-ast")]
 pub(crate) mod ast;
 pub mod atexit;
@@ -74,6 +75,7 @@ pub fn get_module_inits() -> StdlibMap {
         #[cfg(all())]
         {
             "atexit" => atexit::make_module,
+            "_abc" => abc::make_module,
             "_codecs" => codecs::make_module,
             "_collections" => collections::make_module,
             "errno" => errno::make_module,
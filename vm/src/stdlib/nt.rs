@@ -16,7 +16,7 @@ pub(crate) mod module {
         convert::ToPyException,
         function::{Either, OptionalArg},
         ospath::OsPath,
-        stdlib::os::{errno_err, DirFd, FollowSymlinks, SupportFunc, TargetIsDirectory, _os},
+        stdlib::os::{_os, errno_err, DirFd, FollowSymlinks, SupportFunc, TargetIsDirectory},
         PyResult, TryFromObject, VirtualMachine,
     };
     use libc::intptr_t;
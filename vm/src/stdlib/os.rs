@@ -455,9 +455,23 @@ pub(super) mod _os {
         fn perform_on_metadata(
             &self,
             follow_symlinks: FollowSymlinks,
+            file_type_action: fn(fs::FileType) -> bool,
             action: fn(fs::Metadata) -> bool,
             vm: &VirtualMachine,
         ) -> PyResult<bool> {
+            // Fast path: the file type gathered for free while scanning the directory
+            // (backed by `d_type` on platforms that have it) already answers the
+            // question whenever the entry isn't a symlink, since then following
+            // symlinks or not makes no difference. This avoids an extra stat() per
+            // entry, which matters for `glob("**", recursive=True)` over large trees.
+            if let Ok(&file_type) = self.file_type.as_ref() {
+                if !file_type.is_symlink() {
+                    return Ok(file_type_action(file_type));
+                }
+                if !follow_symlinks.0 {
+                    return Ok(false);
+                }
+            }
             match super::fs_metadata(&self.pathval, follow_symlinks.0) {
                 Ok(meta) => Ok(action(meta)),
                 Err(e) => {
@@ -475,6 +489,7 @@ pub(super) mod _os {
         fn is_dir(&self, follow_symlinks: FollowSymlinks, vm: &VirtualMachine) -> PyResult<bool> {
             self.perform_on_metadata(
                 follow_symlinks,
+                |file_type: fs::FileType| -> bool { file_type.is_dir() },
                 |meta: fs::Metadata| -> bool { meta.is_dir() },
                 vm,
             )
@@ -484,6 +499,7 @@ pub(super) mod _os {
         fn is_file(&self, follow_symlinks: FollowSymlinks, vm: &VirtualMachine) -> PyResult<bool> {
             self.perform_on_metadata(
                 follow_symlinks,
+                |file_type: fs::FileType| -> bool { file_type.is_file() },
                 |meta: fs::Metadata| -> bool { meta.is_file() },
                 vm,
             )
@@ -729,6 +745,12 @@ pub(super) mod _os {
         pub st_ctime_ns: i128,
         #[pyarg(any, default)]
         pub st_reparse_tag: u32,
+        // only present on platforms that report file creation time (macOS, the BSDs, Windows)
+        #[pyarg(any, default)]
+        pub st_birthtime: Option<f64>,
+        // Windows-only FILE_ATTRIBUTE_* flags; always 0 elsewhere
+        #[pyarg(any, default)]
+        pub st_file_attributes: u32,
     }
 
     #[pyclass(with(PyStructSequence))]
@@ -764,6 +786,32 @@ pub(super) mod _os {
             #[cfg(not(windows))]
             let st_reparse_tag = 0;
 
+            #[cfg(windows)]
+            let st_file_attributes = stat.st_file_attributes as u32;
+            #[cfg(not(windows))]
+            let st_file_attributes = 0;
+
+            #[cfg(any(
+                windows,
+                target_vendor = "apple",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "openbsd",
+                target_os = "dragonfly"
+            ))]
+            let st_birthtime = Some(
+                stat.st_birthtime as f64 + (stat.st_birthtime_nsec as f64) / (NANOS_PER_SEC as f64),
+            );
+            #[cfg(not(any(
+                windows,
+                target_vendor = "apple",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "openbsd",
+                target_os = "dragonfly"
+            )))]
+            let st_birthtime: Option<f64> = None;
+
             StatResult {
                 st_mode: vm.ctx.new_pyref(stat.st_mode),
                 st_ino: vm.ctx.new_pyref(stat.st_ino),
@@ -782,6 +830,8 @@ pub(super) mod _os {
                 st_mtime_ns: to_ns(mtime),
                 st_ctime_ns: to_ns(ctime),
                 st_reparse_tag,
+                st_birthtime,
+                st_file_attributes,
             }
         }
 
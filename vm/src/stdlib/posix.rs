@@ -23,12 +23,12 @@ pub mod module {
     use crate::{
         builtins::{PyDictRef, PyInt, PyListRef, PyStrRef, PyTupleRef, PyTypeRef},
         convert::{IntoPyException, ToPyObject, TryFromObject},
-        function::{Either, KwArgs, OptionalArg},
+        function::{ArgBytesLike, ArgMemoryBuffer, Either, KwArgs, OptionalArg},
         ospath::{IOErrorBuilder, OsPath, OsPathOrFd},
         stdlib::os::{
-            errno_err, DirFd, FollowSymlinks, SupportFunc, TargetIsDirectory, _os, fs_metadata,
+            _os, errno_err, fs_metadata, DirFd, FollowSymlinks, SupportFunc, TargetIsDirectory,
         },
-        types::{Constructor, Representable},
+        types::{Constructor, PyStructSequence, Representable},
         utils::ToCString,
         AsObject, Py, PyObjectRef, PyPayload, PyResult, VirtualMachine,
     };
@@ -62,6 +62,9 @@ pub mod module {
     use libc::O_DSYNC;
     #[pyattr]
     use libc::{O_CLOEXEC, O_NONBLOCK, WNOHANG};
+    #[cfg(target_os = "linux")]
+    #[pyattr]
+    use libc::{O_DIRECT, O_TMPFILE};
     #[cfg(target_os = "macos")]
     #[pyattr]
     use libc::{O_EVTONLY, O_FSYNC, O_NOFOLLOW_ANY, O_SYMLINK};
@@ -439,6 +442,242 @@ pub mod module {
         )
     }
 
+    #[cfg(not(target_os = "redox"))]
+    #[pyattr]
+    #[pyclass(module = "os", name = "statvfs_result")]
+    #[derive(Debug, PyStructSequence)]
+    struct StatvfsResult {
+        f_bsize: u64,
+        f_frsize: u64,
+        f_blocks: u64,
+        f_bfree: u64,
+        f_bavail: u64,
+        f_files: u64,
+        f_ffree: u64,
+        f_favail: u64,
+        f_flag: u64,
+        f_namemax: u64,
+        f_fsid: u64,
+    }
+
+    #[cfg(not(target_os = "redox"))]
+    #[pyclass(with(PyStructSequence))]
+    impl StatvfsResult {}
+
+    #[cfg(not(target_os = "redox"))]
+    impl From<libc::statvfs> for StatvfsResult {
+        fn from(s: libc::statvfs) -> Self {
+            Self {
+                f_bsize: s.f_bsize as u64,
+                f_frsize: s.f_frsize as u64,
+                f_blocks: s.f_blocks as u64,
+                f_bfree: s.f_bfree as u64,
+                f_bavail: s.f_bavail as u64,
+                f_files: s.f_files as u64,
+                f_ffree: s.f_ffree as u64,
+                f_favail: s.f_favail as u64,
+                f_flag: s.f_flag as u64,
+                f_namemax: s.f_namemax as u64,
+                f_fsid: s.f_fsid as u64,
+            }
+        }
+    }
+
+    #[cfg(not(target_os = "redox"))]
+    #[pyfunction]
+    fn statvfs(path: OsPathOrFd, vm: &VirtualMachine) -> PyResult<StatvfsResult> {
+        let mut stat = std::mem::MaybeUninit::uninit();
+        let ret = match &path {
+            OsPathOrFd::Path(p) => {
+                let cpath = p.clone().into_cstring(vm)?;
+                unsafe { libc::statvfs(cpath.as_ptr(), stat.as_mut_ptr()) }
+            }
+            OsPathOrFd::Fd(fd) => unsafe { libc::fstatvfs(*fd, stat.as_mut_ptr()) },
+        };
+        if ret < 0 {
+            return Err(IOErrorBuilder::new(&io::Error::last_os_error())
+                .filename(path)
+                .into_pyexception(vm));
+        }
+        Ok(unsafe { stat.assume_init() }.into())
+    }
+
+    #[cfg(target_os = "linux")]
+    fn xattr_path_cstring(path: &OsPathOrFd, vm: &VirtualMachine) -> PyResult<Option<CString>> {
+        match path {
+            OsPathOrFd::Path(p) => Ok(Some(p.clone().into_cstring(vm)?)),
+            OsPathOrFd::Fd(_) => Ok(None),
+        }
+    }
+
+    // Grows the buffer and retries on ERANGE, the same strategy CPython's
+    // posixmodule.c uses for the *xattr family of syscalls.
+    #[cfg(target_os = "linux")]
+    fn xattr_retry(
+        mut call: impl FnMut(*mut libc::c_void, usize) -> isize,
+        vm: &VirtualMachine,
+    ) -> PyResult<Vec<u8>> {
+        let mut size = 256usize;
+        loop {
+            let mut buf = vec![0u8; size];
+            let ret = call(buf.as_mut_ptr() as *mut libc::c_void, size);
+            if ret >= 0 {
+                buf.truncate(ret as usize);
+                return Ok(buf);
+            }
+            let err = io::Error::last_os_error();
+            if err.raw_os_error() == Some(libc::ERANGE) {
+                size *= 2;
+                continue;
+            }
+            return Err(err.into_pyexception(vm));
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    #[pyfunction]
+    fn getxattr(
+        path: OsPathOrFd,
+        attribute: PyStrRef,
+        follow_symlinks: FollowSymlinks,
+        vm: &VirtualMachine,
+    ) -> PyResult<Vec<u8>> {
+        let attr = CString::new(attribute.as_str())
+            .map_err(|_| vm.new_value_error("embedded null byte in attribute".to_owned()))?;
+        let cpath = xattr_path_cstring(&path, vm)?;
+        xattr_retry(
+            |buf, size| unsafe {
+                match (&path, &cpath) {
+                    (OsPathOrFd::Fd(fd), _) => libc::fgetxattr(*fd, attr.as_ptr(), buf, size),
+                    (OsPathOrFd::Path(_), Some(cpath)) if follow_symlinks.0 => {
+                        libc::getxattr(cpath.as_ptr(), attr.as_ptr(), buf, size)
+                    }
+                    (OsPathOrFd::Path(_), Some(cpath)) => {
+                        libc::lgetxattr(cpath.as_ptr(), attr.as_ptr(), buf, size)
+                    }
+                    _ => unreachable!(),
+                }
+            },
+            vm,
+        )
+    }
+
+    #[cfg(target_os = "linux")]
+    #[pyfunction]
+    fn setxattr(
+        path: OsPathOrFd,
+        attribute: PyStrRef,
+        value: ArgBytesLike,
+        flags: OptionalArg<i32>,
+        follow_symlinks: FollowSymlinks,
+        vm: &VirtualMachine,
+    ) -> PyResult<()> {
+        let attr = CString::new(attribute.as_str())
+            .map_err(|_| vm.new_value_error("embedded null byte in attribute".to_owned()))?;
+        let flags = flags.unwrap_or(0);
+        let cpath = xattr_path_cstring(&path, vm)?;
+        let ret = value.with_ref(|value| unsafe {
+            match (&path, &cpath) {
+                (OsPathOrFd::Fd(fd), _) => libc::fsetxattr(
+                    *fd,
+                    attr.as_ptr(),
+                    value.as_ptr() as *const libc::c_void,
+                    value.len(),
+                    flags,
+                ),
+                (OsPathOrFd::Path(_), Some(cpath)) if follow_symlinks.0 => libc::setxattr(
+                    cpath.as_ptr(),
+                    attr.as_ptr(),
+                    value.as_ptr() as *const libc::c_void,
+                    value.len(),
+                    flags,
+                ),
+                (OsPathOrFd::Path(_), Some(cpath)) => libc::lsetxattr(
+                    cpath.as_ptr(),
+                    attr.as_ptr(),
+                    value.as_ptr() as *const libc::c_void,
+                    value.len(),
+                    flags,
+                ),
+                _ => unreachable!(),
+            }
+        });
+        if ret < 0 {
+            Err(errno_err(vm))
+        } else {
+            Ok(())
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    #[pyfunction]
+    fn removexattr(
+        path: OsPathOrFd,
+        attribute: PyStrRef,
+        follow_symlinks: FollowSymlinks,
+        vm: &VirtualMachine,
+    ) -> PyResult<()> {
+        let attr = CString::new(attribute.as_str())
+            .map_err(|_| vm.new_value_error("embedded null byte in attribute".to_owned()))?;
+        let cpath = xattr_path_cstring(&path, vm)?;
+        let ret = unsafe {
+            match (&path, &cpath) {
+                (OsPathOrFd::Fd(fd), _) => libc::fremovexattr(*fd, attr.as_ptr()),
+                (OsPathOrFd::Path(_), Some(cpath)) if follow_symlinks.0 => {
+                    libc::removexattr(cpath.as_ptr(), attr.as_ptr())
+                }
+                (OsPathOrFd::Path(_), Some(cpath)) => {
+                    libc::lremovexattr(cpath.as_ptr(), attr.as_ptr())
+                }
+                _ => unreachable!(),
+            }
+        };
+        if ret < 0 {
+            Err(errno_err(vm))
+        } else {
+            Ok(())
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    #[pyfunction]
+    fn listxattr(
+        path: OptionalArg<OsPathOrFd>,
+        follow_symlinks: FollowSymlinks,
+        vm: &VirtualMachine,
+    ) -> PyResult<Vec<PyObjectRef>> {
+        let path = path
+            .into_option()
+            .unwrap_or_else(|| OsPathOrFd::Path(OsPath::new_str(".")));
+        let cpath = xattr_path_cstring(&path, vm)?;
+        let buf = xattr_retry(
+            |buf, size| unsafe {
+                match (&path, &cpath) {
+                    (OsPathOrFd::Fd(fd), _) => {
+                        libc::flistxattr(*fd, buf as *mut libc::c_char, size)
+                    }
+                    (OsPathOrFd::Path(_), Some(cpath)) if follow_symlinks.0 => {
+                        libc::listxattr(cpath.as_ptr(), buf as *mut libc::c_char, size)
+                    }
+                    (OsPathOrFd::Path(_), Some(cpath)) => {
+                        libc::llistxattr(cpath.as_ptr(), buf as *mut libc::c_char, size)
+                    }
+                    _ => unreachable!(),
+                }
+            },
+            vm,
+        )?;
+        Ok(buf
+            .split(|&b| b == 0)
+            .filter(|s| !s.is_empty())
+            .map(|s| {
+                vm.ctx
+                    .new_str(String::from_utf8_lossy(s).into_owned())
+                    .into()
+            })
+            .collect())
+    }
+
     #[derive(FromArgs)]
     struct RegisterAtForkArgs {
         #[pyarg(named, optional)]
@@ -528,6 +767,11 @@ pub mod module {
     }
 
     fn py_os_after_fork_child(vm: &VirtualMachine) {
+        // atexit callbacks registered by the parent shouldn't also fire in the
+        // child on its own, separate exit -- mirrors CPython's atexit module
+        // clearing its callback list as part of fork-child reinitialization.
+        vm.state.atexit_funcs.lock().clear();
+
         let after_forkers_child: Vec<PyObjectRef> = vm.state.after_forkers_child.lock().clone();
         run_at_forkers(after_forkers_child, false, vm);
     }
@@ -616,6 +860,61 @@ pub mod module {
         args.mknod(vm)
     }
 
+    #[cfg(not(target_os = "redox"))]
+    const MKFIFO_DIR_FD: bool = cfg!(not(target_vendor = "apple"));
+
+    #[cfg(not(target_os = "redox"))]
+    #[derive(FromArgs)]
+    struct MkfifoArgs {
+        #[pyarg(any)]
+        path: OsPath,
+        #[pyarg(any, default = "0o666")]
+        mode: libc::mode_t,
+        #[pyarg(flatten)]
+        dir_fd: DirFd<{ MKFIFO_DIR_FD as usize }>,
+    }
+
+    #[cfg(not(target_os = "redox"))]
+    impl MkfifoArgs {
+        fn _mkfifo(self, vm: &VirtualMachine) -> PyResult<i32> {
+            Ok(unsafe { libc::mkfifo(self.path.clone().into_cstring(vm)?.as_ptr(), self.mode) })
+        }
+        #[cfg(not(target_vendor = "apple"))]
+        fn mkfifo(self, vm: &VirtualMachine) -> PyResult<()> {
+            let ret = match self.dir_fd.get_opt() {
+                None => self._mkfifo(vm)?,
+                Some(dir_fd) => unsafe {
+                    libc::mkfifoat(
+                        dir_fd,
+                        self.path.clone().into_cstring(vm)?.as_ptr(),
+                        self.mode,
+                    )
+                },
+            };
+            if ret != 0 {
+                Err(errno_err(vm))
+            } else {
+                Ok(())
+            }
+        }
+        #[cfg(target_vendor = "apple")]
+        fn mkfifo(self, vm: &VirtualMachine) -> PyResult<()> {
+            let [] = self.dir_fd.0;
+            let ret = self._mkfifo(vm)?;
+            if ret != 0 {
+                Err(errno_err(vm))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    #[cfg(not(target_os = "redox"))]
+    #[pyfunction]
+    fn mkfifo(args: MkfifoArgs, vm: &VirtualMachine) -> PyResult<()> {
+        args.mkfifo(vm)
+    }
+
     #[cfg(not(target_os = "redox"))]
     #[pyfunction]
     fn nice(increment: i32, vm: &VirtualMachine) -> PyResult<i32> {
@@ -1726,6 +2025,16 @@ pub mod module {
             SupportFunc::new("fchown", Some(true), None, Some(true)),
             #[cfg(not(target_os = "redox"))]
             SupportFunc::new("mknod", Some(true), Some(MKNOD_DIR_FD), Some(false)),
+            #[cfg(not(target_os = "redox"))]
+            SupportFunc::new("mkfifo", Some(true), Some(MKFIFO_DIR_FD), Some(false)),
+            #[cfg(target_os = "linux")]
+            SupportFunc::new("getxattr", Some(true), None, Some(true)),
+            #[cfg(target_os = "linux")]
+            SupportFunc::new("setxattr", Some(true), None, Some(true)),
+            #[cfg(target_os = "linux")]
+            SupportFunc::new("removexattr", Some(true), None, Some(true)),
+            #[cfg(target_os = "linux")]
+            SupportFunc::new("listxattr", Some(true), None, Some(true)),
             SupportFunc::new("umask", Some(false), Some(false), Some(false)),
             SupportFunc::new("execv", None, None, None),
             SupportFunc::new("pathconf", Some(true), None, None),
@@ -2255,6 +2564,76 @@ pub mod module {
         names
     }
 
+    #[cfg(not(target_os = "redox"))]
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, EnumIter, EnumString)]
+    #[repr(i32)]
+    #[allow(non_camel_case_types)]
+    pub enum ConfstrVar {
+        CS_PATH = libc::_CS_PATH,
+        #[cfg(target_os = "linux")]
+        CS_GNU_LIBC_VERSION = libc::_CS_GNU_LIBC_VERSION,
+        #[cfg(target_os = "linux")]
+        CS_GNU_LIBPTHREAD_VERSION = libc::_CS_GNU_LIBPTHREAD_VERSION,
+    }
+
+    #[cfg(not(target_os = "redox"))]
+    struct ConfstrName(i32);
+
+    #[cfg(not(target_os = "redox"))]
+    impl TryFromObject for ConfstrName {
+        fn try_from_object(vm: &VirtualMachine, obj: PyObjectRef) -> PyResult<Self> {
+            let i = match obj.downcast::<PyInt>() {
+                Ok(int) => int.try_to_primitive(vm)?,
+                Err(obj) => {
+                    let s = PyStrRef::try_from_object(vm, obj)?;
+                    s.as_str().parse::<ConfstrVar>().map_err(|_| {
+                        vm.new_value_error("unrecognized configuration name".to_owned())
+                    })? as i32
+                }
+            };
+            Ok(Self(i))
+        }
+    }
+
+    #[cfg(not(target_os = "redox"))]
+    #[pyfunction]
+    fn confstr(ConfstrName(name): ConfstrName, vm: &VirtualMachine) -> PyResult<Option<String>> {
+        use nix::errno::{self, Errno};
+
+        Errno::clear();
+        let len = unsafe { libc::confstr(name, std::ptr::null_mut(), 0) };
+        if len == 0 {
+            return if errno::errno() == 0 {
+                Ok(None)
+            } else {
+                Err(errno_err(vm))
+            };
+        }
+        let mut buf = vec![0u8; len];
+        let written =
+            unsafe { libc::confstr(name, buf.as_mut_ptr() as *mut libc::c_char, buf.len()) };
+        if written == 0 && errno::errno() != 0 {
+            return Err(errno_err(vm));
+        }
+        buf.truncate(written.saturating_sub(1));
+        Ok(Some(String::from_utf8_lossy(&buf).into_owned()))
+    }
+
+    #[cfg(not(target_os = "redox"))]
+    #[pyattr]
+    fn confstr_names(vm: &VirtualMachine) -> PyDictRef {
+        use strum::IntoEnumIterator;
+        let names = vm.ctx.new_dict();
+        for variant in ConfstrVar::iter() {
+            let key = vm.ctx.new_str(format!("{:?}", variant));
+            let value = vm.ctx.new_int(variant as u8);
+            names
+                .set_item(&*key, value.into(), vm)
+                .expect("dict set_item unexpectedly failed");
+        }
+        names
+    }
+
     #[cfg(any(target_os = "linux", target_os = "macos"))]
     #[derive(FromArgs)]
     struct SendFileArgs<'fd> {
@@ -2343,6 +2722,134 @@ pub mod module {
         Ok(vm.ctx.new_int(written as u64).into())
     }
 
+    #[pyfunction]
+    fn pread(
+        fd: i32,
+        length: usize,
+        offset: crate::common::crt_fd::Offset,
+        vm: &VirtualMachine,
+    ) -> PyResult<Vec<u8>> {
+        let mut buf = vec![0u8; length];
+        let ret = unsafe { libc::pread(fd, buf.as_mut_ptr() as *mut libc::c_void, length, offset) };
+        if ret < 0 {
+            return Err(errno_err(vm));
+        }
+        buf.truncate(ret as usize);
+        Ok(buf)
+    }
+
+    #[pyfunction]
+    fn pwrite(
+        fd: i32,
+        data: ArgBytesLike,
+        offset: crate::common::crt_fd::Offset,
+        vm: &VirtualMachine,
+    ) -> PyResult<usize> {
+        let buf = data.borrow_buf();
+        let ret =
+            unsafe { libc::pwrite(fd, buf.as_ptr() as *const libc::c_void, buf.len(), offset) };
+        if ret < 0 {
+            return Err(errno_err(vm));
+        }
+        Ok(ret as usize)
+    }
+
+    #[pyfunction]
+    fn preadv(
+        fd: i32,
+        buffers: Vec<ArgMemoryBuffer>,
+        offset: crate::common::crt_fd::Offset,
+        flags: OptionalArg<i32>,
+        vm: &VirtualMachine,
+    ) -> PyResult<isize> {
+        let flags = flags.unwrap_or(0);
+        let mut bufs = buffers
+            .iter()
+            .map(|buf| buf.borrow_buf_mut())
+            .collect::<Vec<_>>();
+        let mut iovecs = bufs
+            .iter_mut()
+            .map(|buf| io::IoSliceMut::new(&mut *buf))
+            .collect::<Vec<_>>();
+        let ret = if flags == 0 {
+            unsafe {
+                libc::preadv(
+                    fd,
+                    iovecs.as_mut_ptr() as *mut libc::iovec,
+                    iovecs.len() as i32,
+                    offset,
+                )
+            }
+        } else {
+            #[cfg(target_os = "linux")]
+            unsafe {
+                libc::preadv2(
+                    fd,
+                    iovecs.as_mut_ptr() as *mut libc::iovec,
+                    iovecs.len() as i32,
+                    offset,
+                    flags,
+                )
+            }
+            #[cfg(not(target_os = "linux"))]
+            return Err(vm.new_not_implemented_error(
+                "preadv2 flags are not supported on this platform".to_owned(),
+            ));
+        };
+        if ret < 0 {
+            return Err(errno_err(vm));
+        }
+        Ok(ret as isize)
+    }
+
+    #[pyfunction]
+    fn pwritev(
+        fd: i32,
+        buffers: Vec<ArgBytesLike>,
+        offset: crate::common::crt_fd::Offset,
+        flags: OptionalArg<i32>,
+        vm: &VirtualMachine,
+    ) -> PyResult<isize> {
+        let flags = flags.unwrap_or(0);
+        let bufs = buffers
+            .iter()
+            .map(|buf| buf.borrow_buf())
+            .collect::<Vec<_>>();
+        let iovecs = bufs
+            .iter()
+            .map(|buf| io::IoSlice::new(buf))
+            .collect::<Vec<_>>();
+        let ret = if flags == 0 {
+            unsafe {
+                libc::pwritev(
+                    fd,
+                    iovecs.as_ptr() as *const libc::iovec,
+                    iovecs.len() as i32,
+                    offset,
+                )
+            }
+        } else {
+            #[cfg(target_os = "linux")]
+            unsafe {
+                libc::pwritev2(
+                    fd,
+                    iovecs.as_ptr() as *const libc::iovec,
+                    iovecs.len() as i32,
+                    offset,
+                    flags,
+                )
+            }
+            #[cfg(not(target_os = "linux"))]
+            return Err(vm.new_not_implemented_error(
+                "pwritev2 flags are not supported on this platform".to_owned(),
+            ));
+        };
+        if ret < 0 {
+            return Err(errno_err(vm));
+        }
+        Ok(ret as isize)
+    }
+
     #[cfg(target_os = "linux")]
     unsafe fn sys_getrandom(buf: *mut libc::c_void, buflen: usize, flags: u32) -> isize {
         libc::syscall(libc::SYS_getrandom, buf, buflen, flags as usize) as _
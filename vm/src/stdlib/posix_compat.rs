@@ -12,7 +12,7 @@ pub(crate) mod module {
     use crate::{
         builtins::PyStrRef,
         ospath::OsPath,
-        stdlib::os::{DirFd, SupportFunc, TargetIsDirectory, _os},
+        stdlib::os::{_os, DirFd, SupportFunc, TargetIsDirectory},
         PyObjectRef, PyResult, VirtualMachine,
     };
     use std::env;
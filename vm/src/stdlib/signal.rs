@@ -211,6 +211,25 @@ pub(crate) mod _signal {
         prev_time.unwrap_or(0)
     }
 
+    /// Send a signal to a specific thread, identified the same way `_thread.get_ident()`
+    /// identifies it. Unlike `os.kill`, this targets one thread rather than a whole process.
+    #[cfg(unix)]
+    #[pyfunction]
+    fn pthread_kill(thread_id: u64, signalnum: i32, vm: &VirtualMachine) -> PyResult<()> {
+        signal::assert_in_range(signalnum, vm)?;
+        let handle = crate::stdlib::thread::native_thread_handle(thread_id).ok_or_else(|| {
+            vm.new_exception_msg(
+                vm.ctx.exceptions.process_lookup_error.to_owned(),
+                "signal.pthread_kill() could not find a thread for that identifier".to_owned(),
+            )
+        })?;
+        let ret = unsafe { libc::pthread_kill(handle, signalnum) };
+        if ret != 0 {
+            return Err(std::io::Error::from_raw_os_error(ret).into_pyexception(vm));
+        }
+        Ok(())
+    }
+
     #[pyfunction]
     fn default_int_handler(
         _signum: PyObjectRef,
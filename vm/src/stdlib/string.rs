@@ -5,6 +5,11 @@ pub(crate) use _string::make_module;
 
 #[pymodule]
 mod _string {
+    //! `formatter_parser` and `formatter_field_name_split` are the two primitives
+    //! `string.Formatter` builds on (see `Lib/string.py`). They reproduce CPython's exact
+    //! tokenization - literal text, field name, format spec and conversion, each surfaced
+    //! as a plain tuple - so that `Formatter` subclasses overriding `get_field`/`format_field`
+    //! see the same shape as on CPython.
     use crate::common::ascii;
     use crate::{
         builtins::{PyList, PyStrRef},
@@ -191,8 +191,7 @@ mod symtable {
 
         #[pymethod]
         fn is_nested(&self) -> bool {
-            // TODO
-            false
+            self.namespaces.iter().any(|table| table.is_nested)
         }
 
         #[pymethod]
@@ -1,6 +1,6 @@
 use crate::{builtins::PyModule, convert::ToPyObject, Py, PyResult, VirtualMachine};
 
-pub(crate) use sys::{UnraisableHookArgs, __module_def, DOC, MAXSIZE, MULTIARCH};
+pub(crate) use sys::{__module_def, UnraisableHookArgs, DOC, MAXSIZE, MULTIARCH};
 
 #[pymodule]
 mod sys {
@@ -136,6 +136,21 @@ mod sys {
         )
     }
 
+    /// Not a standard CPython attribute: the names of the modules frozen into this
+    /// interpreter, for packaging tools that need to reason about the environment
+    /// without going through `_imp` (which importlib treats as private).
+    #[pyattr]
+    fn _rustpython_frozen_module_names(vm: &VirtualMachine) -> PyTupleRef {
+        let mut module_names: Vec<_> = vm.state.frozen.keys().copied().collect();
+        module_names.sort_unstable();
+        vm.ctx.new_tuple(
+            module_names
+                .into_iter()
+                .map(|n| vm.ctx.new_str(n).into())
+                .collect(),
+        )
+    }
+
     #[pyattr]
     fn byteorder(vm: &VirtualMachine) -> PyStrRef {
         // https://doc.rust-lang.org/reference/conditional-compilation.html#target_endian
@@ -299,8 +314,18 @@ mod sys {
     }
 
     #[pyfunction]
-    fn audit(_args: FuncArgs) {
-        // TODO: sys.audit implementation
+    fn audit(args: FuncArgs, vm: &VirtualMachine) -> PyResult<()> {
+        let mut args = args.args.into_iter();
+        let event = args.next().ok_or_else(|| {
+            vm.new_type_error("audit() missing 1 required positional argument: 'event'".to_owned())
+        })?;
+        let event = event.str(vm)?.as_str().to_owned();
+        vm.audit(&event, PosArgs::new(args.collect()))
+    }
+
+    #[pyfunction]
+    fn addaudithook(hook: PyObjectRef, vm: &VirtualMachine) {
+        vm.state.audit_hooks.lock().push(hook);
     }
 
     #[pyfunction]
@@ -416,9 +441,20 @@ mod sys {
 
     #[pyfunction]
     fn getrefcount(obj: PyObjectRef) -> usize {
+        // approximate: counts RustPython's own Rc strong references, which is close to but not
+        // exactly CPython's refcount (e.g. it excludes the temporary reference this call itself
+        // would add in CPython's C API).
         obj.strong_count()
     }
 
+    #[pyfunction]
+    fn getallocatedblocks(_vm: &VirtualMachine) -> usize {
+        // CPython counts live blocks tracked by its pymalloc arena. RustPython allocates
+        // objects through the system allocator with no equivalent global bookkeeping, so there
+        // is nothing meaningful to report; always return 0 rather than guessing.
+        0
+    }
+
     #[pyfunction]
     fn getrecursionlimit(vm: &VirtualMachine) -> usize {
         vm.recursion_limit.get()
@@ -1,4 +1,6 @@
 //! Implementation of the _thread module
+#[cfg(unix)]
+pub(crate) use _thread::native_thread_handle;
 #[cfg_attr(target_arch = "wasm32", allow(unused_imports))]
 pub(crate) use _thread::{make_module, RawRMutex};
 
@@ -259,7 +261,37 @@ pub(crate) mod _thread {
 
     #[pyfunction]
     fn get_ident() -> u64 {
-        thread_to_id(&thread::current())
+        let id = thread_to_id(&thread::current());
+        #[cfg(unix)]
+        register_current_native_thread(id);
+        id
+    }
+
+    // Maps the `u64` identifiers handed out by `get_ident`/`start_new_thread` to the
+    // underlying `pthread_t`, so `signal.pthread_kill` (which CPython specifies in terms
+    // of the same thread identifiers) has something to actually signal. Populated lazily:
+    // a thread needs to have called `get_ident()` or been started via `start_new_thread`
+    // before another thread can `pthread_kill` it.
+    #[cfg(unix)]
+    fn native_thread_idents(
+    ) -> &'static crate::common::lock::PyMutex<std::collections::HashMap<u64, libc::pthread_t>>
+    {
+        static IDENTS: std::sync::OnceLock<
+            crate::common::lock::PyMutex<std::collections::HashMap<u64, libc::pthread_t>>,
+        > = std::sync::OnceLock::new();
+        IDENTS.get_or_init(Default::default)
+    }
+
+    #[cfg(unix)]
+    fn register_current_native_thread(id: u64) {
+        native_thread_idents()
+            .lock()
+            .insert(id, unsafe { libc::pthread_self() });
+    }
+
+    #[cfg(unix)]
+    pub(crate) fn native_thread_handle(id: u64) -> Option<libc::pthread_t> {
+        native_thread_idents().lock().get(&id).copied()
     }
 
     fn thread_to_id(t: &thread::Thread) -> u64 {
@@ -322,7 +354,16 @@ pub(crate) mod _thread {
             .map_err(|err| err.to_pyexception(vm))
     }
 
+    // CPython accelerates `threading.excepthook` with a native `_thread._excepthook`/
+    // `_thread._ExceptHookArgs`, but that's a pure speed optimization: `Lib/threading.py`
+    // already falls back to an equivalent pure-Python `excepthook`/`ExceptHookArgs` when the
+    // `_thread` import fails, so `threading.Thread` exceptions are routed there either way. The
+    // raw `start_new_thread` primitive below has no `Thread` object or `excepthook` to call
+    // through, so its uncaught exceptions go straight to `vm.run_unraisable` instead, same as
+    // CPython's own `t_bootstrap`.
     fn run_thread(func: ArgCallable, args: FuncArgs, vm: &VirtualMachine) {
+        #[cfg(unix)]
+        register_current_native_thread(thread_to_id(&thread::current()));
         match func.invoke(args, vm) {
             Ok(_obj) => {}
             Err(e) if e.fast_isinstance(vm.ctx.exceptions.system_exit) => {}
@@ -189,6 +189,19 @@ pub(crate) type NewFunc = fn(PyTypeRef, FuncArgs, &VirtualMachine) -> PyResult;
 pub(crate) type InitFunc = fn(PyObjectRef, FuncArgs, &VirtualMachine) -> PyResult<()>;
 pub(crate) type DelFunc = fn(&PyObject, &VirtualMachine) -> PyResult<()>;
 
+/// Classification of a class attribute for the instance-attribute lookup fast path
+/// (`PyObject::generic_getattr_opt`): whether it's a data descriptor (its `__get__` wins over
+/// the instance `__dict__`), a non-data descriptor (the instance `__dict__` wins), or a plain
+/// value. `PyType::cached_attr_kind` caches this per attribute name so the common case of
+/// repeatedly accessing the same attribute doesn't re-walk the descriptor's own class `mro`
+/// looking for `__set__`/`__delete__` every time.
+#[derive(Clone, Copy)]
+pub(crate) enum AttrKind {
+    Plain,
+    NonData(DescrGetFunc),
+    Data(DescrGetFunc),
+}
+
 // slot_sq_length
 pub(crate) fn len_wrapper(obj: &PyObject, vm: &VirtualMachine) -> PyResult<usize> {
     let ret = vm.call_special_method(obj, identifier!(vm, __len__), ())?;
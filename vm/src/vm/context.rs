@@ -92,6 +92,7 @@ declare_const_name! {
     __await__,
     __bases__,
     __bool__,
+    __buffer__,
     __build_class__,
     __builtins__,
     __bytes__,
@@ -460,6 +461,20 @@ impl Context {
         .unwrap()
     }
 
+    /// Start building a heap type (a Python class) from Rust at runtime, without going through
+    /// the `#[pyclass]` macro. Intended for embedders that need to expose dynamically-defined
+    /// classes to Python code.
+    pub fn class_builder<'a>(&'a self, name: &str, base: PyTypeRef) -> ClassBuilder<'a> {
+        ClassBuilder {
+            ctx: self,
+            module: None,
+            name: name.to_owned(),
+            bases: vec![base],
+            slots: PyTypeSlots::heap_default(),
+            methods: Vec::new(),
+        }
+    }
+
     pub fn new_exception_type(
         &self,
         module: &str,
@@ -580,6 +595,78 @@ impl Context {
     }
 }
 
+/// Builder returned by [`Context::class_builder`] for defining a Python class from Rust at
+/// runtime. See [`Context::class_builder`].
+pub struct ClassBuilder<'a> {
+    ctx: &'a Context,
+    module: Option<&'a str>,
+    name: String,
+    bases: Vec<PyTypeRef>,
+    slots: PyTypeSlots,
+    methods: Vec<(&'static str, PyRef<HeapMethodDef>)>,
+}
+
+impl<'a> ClassBuilder<'a> {
+    /// Set the additional bases of the class; `base` passed to [`Context::class_builder`]
+    /// remains the first base.
+    pub fn bases(mut self, bases: impl IntoIterator<Item = PyTypeRef>) -> Self {
+        self.bases.truncate(1);
+        self.bases.extend(bases);
+        self
+    }
+
+    pub fn slots(mut self, slots: PyTypeSlots) -> Self {
+        self.slots = slots;
+        self
+    }
+
+    pub fn module(mut self, module: &'a str) -> Self {
+        self.module = Some(module);
+        self
+    }
+
+    /// Add a method backed by a Rust closure or function.
+    pub fn method<F, FKind>(mut self, name: &'static str, f: F) -> Self
+    where
+        F: IntoPyNativeFn<FKind>,
+    {
+        let def = self
+            .ctx
+            .new_method_def(name, f, PyMethodFlags::METHOD, None);
+        self.methods.push((name, def));
+        self
+    }
+
+    /// Create the class. The returned type is leaked for the lifetime of the process, the same
+    /// as a type defined with `#[pyclass]`.
+    pub fn build(self, vm: &VirtualMachine) -> PyResult<PyTypeRef> {
+        let mut attrs = PyAttributes::default();
+        if let Some(module) = self.module {
+            attrs.insert(
+                identifier!(self.ctx, __module__),
+                self.ctx.new_str(module).into(),
+            );
+        }
+        let typ = PyType::new_heap(
+            &self.name,
+            self.bases,
+            attrs,
+            self.slots,
+            self.ctx.types.type_type.to_owned(),
+            self.ctx,
+        )
+        .map_err(|e| vm.new_type_error(e))?;
+        let class = PyRef::leak(typ);
+        for (name, def) in self.methods {
+            class.set_attr(
+                self.ctx.intern_str(name),
+                def.build_method(class, vm).into(),
+            );
+        }
+        Ok(class.to_owned())
+    }
+}
+
 impl AsRef<Context> for Context {
     fn as_ref(&self) -> &Self {
         self
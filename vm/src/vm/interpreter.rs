@@ -107,9 +107,10 @@ impl Interpreter {
 
     /// Finalize vm and turns an exception to exit code.
     ///
-    /// Finalization steps including 4 steps:
+    /// Finalization steps including 5 steps:
     /// 1. Flush stdout and stderr.
     /// 1. Handle exit exception and turn it to exit code.
+    /// 1. Wait for non-daemon threads to finish (`threading._shutdown`).
     /// 1. Run atexit exit functions.
     /// 1. Mark vm as finalized.
     ///
@@ -125,6 +126,8 @@ impl Interpreter {
                 0
             };
 
+            wait_for_thread_shutdown(vm);
+
             atexit::_run_exitfuncs(vm);
 
             vm.state.finalizing.store(true, Ordering::Release);
@@ -136,6 +139,29 @@ impl Interpreter {
     }
 }
 
+/// Joins non-daemon threads before the rest of finalization runs, mirroring
+/// CPython's `wait_for_thread_shutdown`. Only bothers if `threading` was
+/// ever imported -- otherwise no non-main thread could have been started
+/// through it, so there's nothing to wait for.
+fn wait_for_thread_shutdown(vm: &VirtualMachine) {
+    let sys_modules = match vm.sys_module.get_attr("modules", vm) {
+        Ok(modules) => modules,
+        Err(_) => return,
+    };
+    let Ok(threading) = sys_modules.get_item("threading", vm) else {
+        return;
+    };
+    if let Ok(shutdown) = threading.get_attr("_shutdown", vm) {
+        if let Err(e) = shutdown.call((), vm) {
+            vm.run_unraisable(
+                e,
+                Some("Error in threading._shutdown".to_owned()),
+                threading,
+            );
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -27,7 +27,7 @@ use crate::{
     convert::ToPyObject,
     frame::{ExecutionResult, Frame, FrameRef},
     frozen::FrozenModule,
-    function::{ArgMapping, FuncArgs, PySetterValue},
+    function::{ArgMapping, FuncArgs, IntoFuncArgs, PySetterValue},
     import,
     protocol::PyIterIter,
     scope::Scope,
@@ -48,7 +48,7 @@ use std::{
     collections::{HashMap, HashSet},
 };
 
-pub use context::Context;
+pub use context::{ClassBuilder, Context};
 pub use interpreter::Interpreter;
 pub(crate) use method::PyMethod;
 pub use setting::Settings;
@@ -77,6 +77,10 @@ pub struct VirtualMachine {
     pub state: PyRc<PyGlobalState>,
     pub initialized: bool,
     recursion_depth: Cell<usize>,
+    /// Embedder-provided hook run once, after core initialization but before
+    /// `site` (and therefore any user code) is processed. See
+    /// `rustpython::InterpreterConfig::presite_hook` for how to install one.
+    presite_hook: RefCell<Option<Box<dyn FnOnce(&VirtualMachine)>>>,
 }
 
 #[derive(Debug, Default)]
@@ -101,6 +105,14 @@ pub struct PyGlobalState {
     pub after_forkers_child: PyMutex<Vec<PyObjectRef>>,
     pub after_forkers_parent: PyMutex<Vec<PyObjectRef>>,
     pub int_max_str_digits: AtomicCell<usize>,
+    pub audit_hooks: PyMutex<Vec<PyObjectRef>>,
+    /// Bumped every time an ABC's virtual-subclass registry changes, so that cached
+    /// `_abc`/`abc.ABCMeta` subclass checks elsewhere know their cache is stale.
+    pub abc_invalidation_counter: AtomicCell<u64>,
+    /// Bumped every time any type's attribute dict is assigned or deleted through
+    /// `type.__setattr__`/`__delattr__`, so `PyType::cached_attr_kind`'s per-attribute
+    /// data/non-data/plain classification cache knows its entries are stale.
+    pub attr_cache_version: AtomicCell<u64>,
 }
 
 pub fn process_hash_secret_seed() -> u32 {
@@ -186,9 +198,13 @@ impl VirtualMachine {
                 after_forkers_child: PyMutex::default(),
                 after_forkers_parent: PyMutex::default(),
                 int_max_str_digits,
+                audit_hooks: PyMutex::default(),
+                abc_invalidation_counter: AtomicCell::new(0),
+                attr_cache_version: AtomicCell::new(0),
             }),
             initialized: false,
             recursion_depth: Cell::new(0),
+            presite_hook: RefCell::new(None),
         };
 
         if vm.state.hash_secret.hash_str("")
@@ -461,13 +477,53 @@ impl VirtualMachine {
         }
     }
 
+    /// Below this much headroom on the native stack, treat it as exhausted: raising
+    /// `sys.setrecursionlimit()` shouldn't be able to turn Python recursion into a real
+    /// Rust stack overflow.
+    const STACK_SAFETY_MARGIN: usize = 256 * 1024;
+
     // To be called right before raising the recursion depth.
     fn check_recursive_call(&self, _where: &str) -> PyResult<()> {
         if self.recursion_depth.get() >= self.recursion_limit.get() {
-            Err(self.new_recursion_error(format!("maximum recursion depth exceeded {_where}")))
-        } else {
-            Ok(())
+            return Err(self.new_recursion_error(format!(
+                "maximum recursion depth exceeded {_where}{}",
+                self.innermost_frames_summary()
+            )));
         }
+        if crate::stack_probe::remaining_bytes()
+            .is_some_and(|remaining| remaining < Self::STACK_SAFETY_MARGIN)
+        {
+            return Err(self.new_recursion_error(format!(
+                "maximum recursion depth exceeded {_where} (native stack nearly exhausted){}",
+                self.innermost_frames_summary()
+            )));
+        }
+        Ok(())
+    }
+
+    /// How many innermost frame names to name in a `RecursionError` message.
+    const RECURSION_ERROR_SUMMARY_FRAMES: usize = 5;
+
+    /// A compact ", in <innermost> < <next> < ..." suffix naming the most recently entered
+    /// frames, so a RecursionError points at the actual cycle (e.g. `a < b < a < b < ...`)
+    /// instead of just the generic "maximum recursion depth exceeded".
+    fn innermost_frames_summary(&self) -> String {
+        let frames = self.frames.borrow();
+        if frames.is_empty() {
+            return String::new();
+        }
+        let names: Vec<&str> = frames
+            .iter()
+            .rev()
+            .take(Self::RECURSION_ERROR_SUMMARY_FRAMES)
+            .map(|f| f.code.obj_name.as_str())
+            .collect();
+        let ellipsis = if frames.len() > names.len() {
+            " < ..."
+        } else {
+            ""
+        };
+        format!(", in {}{}", names.join(" < "), ellipsis)
     }
 
     pub fn current_frame(&self) -> Option<Ref<FrameRef>> {
@@ -545,6 +601,19 @@ impl VirtualMachine {
         from_list: PyTupleTyped<PyStrRef>,
         level: usize,
     ) -> PyResult {
+        // matches CPython's documented `import` event signature:
+        // (module, filename, sys.path, sys.meta_path, sys.path_hooks)
+        self.audit(
+            "import",
+            (
+                module.to_owned(),
+                self.ctx.none(),
+                self.sys_module.get_attr("path", self)?,
+                self.sys_module.get_attr("meta_path", self)?,
+                self.sys_module.get_attr("path_hooks", self)?,
+            ),
+        )?;
+
         // if the import inputs seem weird, e.g a package import or something, rather than just
         // a straight `import ident`
         let weird = module.as_str().contains('.') || level != 0 || !from_list.is_empty();
@@ -868,12 +937,48 @@ impl VirtualMachine {
         Ok(())
     }
 
+    /// Install a hook to be run by [`run_presite_hook`](Self::run_presite_hook), i.e. exactly
+    /// between core VM initialization and `site`/main execution. Embedders should prefer
+    /// `rustpython::InterpreterConfig::presite_hook` over calling this directly.
+    pub fn set_presite_hook(&self, hook: Box<dyn FnOnce(&VirtualMachine)>) {
+        *self.presite_hook.borrow_mut() = Some(hook);
+    }
+
+    /// Run the presite hook installed by [`set_presite_hook`](Self::set_presite_hook), if any.
+    /// Must be called exactly once, before `site` is imported.
+    pub fn run_presite_hook(&self) {
+        if let Some(hook) = self.presite_hook.borrow_mut().take() {
+            hook(self);
+        }
+    }
+
     pub fn run_module(&self, module: &str) -> PyResult<()> {
         let runpy = self.import("runpy", 0)?;
         let run_module_as_main = runpy.get_attr("_run_module_as_main", self)?;
         run_module_as_main.call((module,), self)?;
         Ok(())
     }
+
+    /// Raise the named audit event to every hook installed via `sys.addaudithook`,
+    /// mirroring `PySys_Audit` in CPython. Any hook may veto the operation by
+    /// raising, in which case that exception propagates to the caller.
+    pub fn audit(&self, event: &str, args: impl IntoFuncArgs) -> PyResult<()> {
+        // Hooks are allowed to register further hooks (`sys.addaudithook`) or
+        // trigger other audited operations from inside their callback, both
+        // of which re-enter this function on the same thread -- so the hooks
+        // must be cloned out and the lock dropped before any of them are
+        // called, or we deadlock on the non-reentrant `audit_hooks` mutex.
+        let hooks = self.state.audit_hooks.lock().clone();
+        if hooks.is_empty() {
+            return Ok(());
+        }
+        let event = self.ctx.new_str(event).into();
+        let args = self.ctx.new_tuple(args.into_args(self).args);
+        for hook in hooks.iter() {
+            hook.call((event.clone(), args.clone()), self)?;
+        }
+        Ok(())
+    }
 }
 
 impl AsRef<Context> for VirtualMachine {
@@ -10,7 +10,7 @@ use crate::{
 
 pub struct WarningsState {
     filters: PyListRef,
-    _once_registry: PyDictRef,
+    once_registry: PyDictRef,
     default_action: PyStrRef,
     filters_version: usize,
 }
@@ -31,7 +31,7 @@ impl WarningsState {
     pub fn init_state(ctx: &Context) -> WarningsState {
         WarningsState {
             filters: Self::create_filter(ctx),
-            _once_registry: PyDict::new_ref(ctx),
+            once_registry: PyDict::new_ref(ctx),
             default_action: ctx.new_str("default"),
             filters_version: 0,
         }
@@ -164,28 +164,23 @@ fn already_warned(
     should_set: bool,
     vm: &VirtualMachine,
 ) -> PyResult<bool> {
+    let current_version = vm.state.warnings.filters_version;
     let version_obj = registry.get_item(identifier!(&vm.ctx, version), vm).ok();
-    let filters_version = vm.ctx.new_int(vm.state.warnings.filters_version).into();
-
-    match version_obj {
-        Some(version_obj)
-            if version_obj.try_int(vm).is_ok() || version_obj.is(&filters_version) =>
-        {
-            let already_warned = registry.get_item(key.as_ref(), vm)?;
+    let up_to_date = version_obj.is_some_and(|version_obj| {
+        version_obj
+            .try_int(vm)
+            .is_ok_and(|v| v.as_u32_mask() as usize == current_version)
+    });
+
+    if up_to_date {
+        if let Ok(already_warned) = registry.get_item(key.as_ref(), vm) {
             if already_warned.is_true(vm)? {
                 return Ok(true);
             }
         }
-        _ => {
-            let registry = registry.dict();
-            if let Some(registry) = registry.as_ref() {
-                registry.clear();
-                let r = registry.set_item("version", filters_version, vm);
-                if r.is_err() {
-                    return Ok(false);
-                }
-            }
-        }
+    } else if let Ok(registry) = PyDictRef::try_from_object(vm, registry.clone()) {
+        registry.clear();
+        registry.set_item("version", vm.ctx.new_int(current_version).into(), vm)?;
     }
 
     /* This warning wasn't found in the registry, set it. */
@@ -193,9 +188,8 @@ fn already_warned(
         return Ok(false);
     }
 
-    let item = vm.ctx.true_value.clone().into();
-    let _ = registry.set_item(key.as_ref(), item, vm); // ignore set error
-    Ok(true)
+    registry.set_item(key.as_ref(), vm.ctx.true_value.clone().into(), vm)?;
+    Ok(false)
 }
 
 fn normalize_module(filename: &Py<PyStr>, vm: &VirtualMachine) -> Option<PyObjectRef> {
@@ -263,7 +257,8 @@ fn warn_explicit(
         &vm.ctx,
     );
 
-    if !vm.is_none(registry.as_object()) && already_warned(registry, key.into_object(), false, vm)?
+    if !vm.is_none(registry.as_object())
+        && already_warned(registry.clone(), key.clone().into_object(), false, vm)?
     {
         return Ok(());
     }
@@ -277,15 +272,56 @@ fn warn_explicit(
         item,
         vm,
     )?;
+    let action = action.str(vm)?.as_str().to_owned();
 
-    if action.str(vm)?.as_str().eq("error") {
+    if action == "error" {
         return Err(vm.new_type_error(message.to_string()));
     }
 
-    if action.str(vm)?.as_str().eq("ignore") {
+    if action == "ignore" {
         return Ok(());
     }
 
+    // "once" and "module" fold the lineno out of the key so every call site within the
+    // once-registry's scope (global for "once", the warning module's `__warningregistry__` for
+    // "module") only shows the first time; "default" (and anything else we don't recognize,
+    // matching CPython) keeps the lineno so each call site warns once. "always" never records
+    // anything and always falls through to showing the warning.
+    if action == "once" {
+        let once_key = PyTuple::new_ref(
+            vec![
+                vm.ctx.new_str(text).into(),
+                category.as_object().to_owned(),
+                vm.ctx.new_int(0).into(),
+            ],
+            &vm.ctx,
+        );
+        if already_warned(
+            vm.state.warnings.once_registry.clone().into(),
+            once_key.into_object(),
+            true,
+            vm,
+        )? {
+            return Ok(());
+        }
+    } else if action == "module" {
+        let module_key = PyTuple::new_ref(
+            vec![
+                vm.ctx.new_str(text).into(),
+                category.as_object().to_owned(),
+                vm.ctx.new_int(0).into(),
+            ],
+            &vm.ctx,
+        );
+        if already_warned(registry.clone(), module_key.into_object(), true, vm)? {
+            return Ok(());
+        }
+    } else if action != "always" {
+        if already_warned(registry.clone(), key.into_object(), true, vm)? {
+            return Ok(());
+        }
+    }
+
     call_show_warning(
         // t_state,
         category,
@@ -374,7 +410,7 @@ fn setup_context(
                 break;
             }
             if let Some(tmp) = f {
-                f = tmp.f_back(vm);
+                f = tmp.f_back();
             } else {
                 break;
             }
@@ -386,7 +422,7 @@ fn setup_context(
                 break;
             }
             if let Some(tmp) = f {
-                f = tmp.next_external_frame(vm);
+                f = tmp.next_external_frame();
             } else {
                 break;
             }
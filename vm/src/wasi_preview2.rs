@@ -0,0 +1,27 @@
+//! WASI Preview 2 (component model) support status (work in progress).
+//!
+//! Today's `wasm32-wasi` target (and the `target_os = "wasi"` cfgs scattered across
+//! [`crate::stdlib::os`], [`crate::stdlib::socket`] and [`crate::stdlib::time`]) builds against
+//! WASI Preview 1: a flat set of `wasi-libc` syscalls (`fd_*`, `clock_time_get`, `sock_*`) that
+//! Rust's `std` maps onto `std::fs`/`std::net`/`std::time` more or less the way it does for a
+//! regular POSIX target. That's why plain files, directory preopens via `std::fs`, and
+//! `CLOCK_MONOTONIC`/`CLOCK_PROCESS_CPUTIME_ID` already work there (see `get_process_time` in
+//! `crate::stdlib::time`).
+//!
+//! Preview 2 is a different, component-model ABI (interfaces described in WIT, instantiated
+//! through `wasi:sockets`, `wasi:clocks`, `wasi:filesystem`, ...) targeted by the separate
+//! `wasm32-wasip2` target triple, not `wasm32-wasi`. Rust's `std` does not bridge to it the way
+//! it does Preview 1 -- using it means either building against that triple with a `std` new
+//! enough to support it, or binding the component interfaces directly with `wit-bindgen`, neither
+//! of which is a change this crate's existing `target_os = "wasi"` cfgs can grow into
+//! incrementally. In particular, `_socket`'s current implementation (`crate::stdlib::socket`)
+//! assumes BSD-style `socket(2)`/`connect(2)` calls are available, which Preview 2 replaces with
+//! the `wasi:sockets` interface's handle-based, non-blocking-by-default model; adapting it is a
+//! rewrite of that module's platform layer, not an additive one.
+//!
+//! This module exists to record that boundary and give a Preview-2-aware build a version to
+//! check against once one lands; it does not itself add Preview 2 support.
+
+/// Bumped if/when this crate gains code that assumes a particular shape of WASI Preview 2
+/// support (e.g. which `wasi:*` interfaces are bound). Currently always `0`: no such code exists.
+pub const PREVIEW2_SUPPORT_LEVEL: u32 = 0;
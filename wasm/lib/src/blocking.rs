@@ -0,0 +1,43 @@
+//! Hooks for blocking stdlib calls (`time.sleep`, `input`, ...) running under `rustpython_wasm`
+//! (work in progress).
+//!
+//! A real fix for these calls looks like running the interpreter on a thread that can be
+//! suspended and resumed by the browser's event loop without unwinding the Rust call stack --
+//! either via Binaryen's Asyncify transform (which rewrites the compiled `.wasm` to save/restore
+//! the stack around designated import calls) or by moving interpretation to a Web Worker and
+//! using `Atomics.wait` for the blocking wait. Both are build-pipeline changes (an extra
+//! `wasm-opt --asyncify` pass, or a worker + `SharedArrayBuffer` setup) that live outside this
+//! crate's `cargo build`/`wasm-bindgen` step, not something addressable by adding Rust code to
+//! `rustpython_wasm` alone.
+//!
+//! What *is* addressable from here is giving embedders a place to plug in whatever blocking
+//! strategy their page supports, so that once the build-pipeline half exists, the VM-side wiring
+//! doesn't need to change. [`BlockingHook::block_on`] is that extension point: it's handed the
+//! requested [`Duration`] and is expected to not return until that much wall-clock time has
+//! passed (or the operation should be cancelled), in whatever way the embedder's page can
+//! actually suspend -- today that's limited to techniques that don't require unwinding Rust's
+//! stack, such as `Atomics.wait` against a `SharedArrayBuffer` from a worker. The default
+//! [`NoopBlockingHook`] returns immediately, which is what every call site falls back to as of
+//! this module's introduction.
+use std::time::Duration;
+
+/// Strategy for blocking the current thread of a `rustpython_wasm` instance for roughly `dur`,
+/// used by stdlib calls like `time.sleep` that can't simply return early.
+///
+/// See the module docs for why the default implementation can't do better than return
+/// immediately, and what a real implementation needs from the embedding page.
+pub trait BlockingHook {
+    /// Block for approximately `dur`. Implementations that cannot actually block (the default)
+    /// should return immediately rather than busy-loop, since busy-looping would still freeze
+    /// the browser tab's main thread for `dur` regardless.
+    fn block_on(&self, dur: Duration);
+}
+
+/// The default [`BlockingHook`]: does not block at all. This matches today's behavior of
+/// blocking calls effectively being no-ops under `rustpython_wasm`.
+#[derive(Default)]
+pub struct NoopBlockingHook;
+
+impl BlockingHook for NoopBlockingHook {
+    fn block_on(&self, _dur: Duration) {}
+}
@@ -10,12 +10,12 @@ mod _js {
     };
     use js_sys::{Array, Object, Promise, Reflect};
     use rustpython_vm::{
-        builtins::{PyBaseExceptionRef, PyFloat, PyStrRef, PyType, PyTypeRef},
+        builtins::{PyBaseExceptionRef, PyFloat, PyStr, PyStrRef, PyType, PyTypeRef},
         convert::{IntoObject, ToPyObject},
-        function::{ArgCallable, OptionalArg, OptionalOption, PosArgs},
+        function::{ArgCallable, OptionalArg, OptionalOption, PosArgs, PySetterValue},
         protocol::PyIterReturn,
-        types::{IterNext, Representable, SelfIter},
-        Py, PyObjectRef, PyPayload, PyRef, PyResult, TryFromObject, VirtualMachine,
+        types::{GetAttr, IterNext, Representable, SelfIter, SetAttr},
+        AsObject, Py, PyObjectRef, PyPayload, PyRef, PyResult, TryFromObject, VirtualMachine,
     };
     use std::{cell, fmt, future};
     use wasm_bindgen::{closure::Closure, prelude::*, JsCast};
@@ -90,7 +90,7 @@ mod _js {
         }
     }
 
-    #[pyclass(with(Representable))]
+    #[pyclass(with(Representable, GetAttr, SetAttr))]
     impl PyJsValue {
         #[inline]
         pub fn new(value: impl Into<JsValue>) -> PyJsValue {
@@ -273,6 +273,51 @@ mod _js {
         }
     }
 
+    // Lets Python code read a JS value's properties with plain attribute access
+    // (`js_obj.foo`) as an alternative to the explicit `get_prop`/`set_prop` methods. Methods
+    // and other attributes defined on the `JSValue` type itself (e.g. `get_prop`, `call`) still
+    // take priority, the same way `__getattr__` only kicks in once the generic lookup fails.
+    impl GetAttr for PyJsValue {
+        fn getattro(zelf: &Py<Self>, name: &Py<PyStr>, vm: &VirtualMachine) -> PyResult {
+            if let Some(attr) = zelf.as_object().generic_getattr_opt(name, None, vm)? {
+                return Ok(attr);
+            }
+            let js_name: JsValue = name.as_str().into();
+            if has_prop(&zelf.value, &js_name).map_err(|err| new_js_error(vm, err))? {
+                get_prop(&zelf.value, &js_name)
+                    .map(|value| PyJsValue::new(value).to_pyobject(vm))
+                    .map_err(|err| new_js_error(vm, err))
+            } else {
+                Err(vm.new_attribute_error(format!("No attribute {name} on JS value")))
+            }
+        }
+    }
+
+    impl SetAttr for PyJsValue {
+        fn setattro(
+            zelf: &Py<Self>,
+            name: &Py<PyStr>,
+            value: PySetterValue,
+            vm: &VirtualMachine,
+        ) -> PyResult<()> {
+            let name: JsValue = name.as_str().into();
+            match value {
+                PySetterValue::Assign(value) => {
+                    set_prop(&zelf.value, &name, &convert::py_to_js(vm, value))
+                        .map_err(|err| new_js_error(vm, err))
+                }
+                PySetterValue::Delete => Reflect::delete_property(
+                    zelf.value
+                        .dyn_ref::<Object>()
+                        .ok_or_else(|| vm.new_type_error("JS value is not an object".to_owned()))?,
+                    &name,
+                )
+                .map(drop)
+                .map_err(|err| new_js_error(vm, err)),
+            }
+        }
+    }
+
     #[derive(FromArgs)]
     struct CallOptions {
         #[pyarg(named, default)]
@@ -1,6 +1,8 @@
+pub mod blocking;
 pub mod browser_module;
 pub mod convert;
 pub mod js_module;
+pub mod vfs;
 pub mod vm_class;
 pub mod wasm_builtins;
 
@@ -126,8 +128,10 @@ pub mod eval {
 /// Re-export as `pub use rustpython_wasm::exports::*;` in the root of your crate if you want your
 /// wasm module to mimic rustpython_wasm's API
 pub mod exports {
+    pub use crate::blocking::{BlockingHook, NoopBlockingHook};
     pub use crate::convert::PyError;
     pub use crate::eval::{eval_py, exec_py, exec_single_py};
+    pub use crate::vfs::{InMemoryVfs, Vfs};
     pub use crate::vm_class::{VMStore, WASMVirtualMachine};
 }
 
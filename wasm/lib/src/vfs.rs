@@ -0,0 +1,56 @@
+//! A pluggable in-memory filesystem for `rustpython_wasm` (work in progress).
+//!
+//! Neither `open()` nor the rest of the `os`/`io` modules talk to this yet -- they still go
+//! through `std::fs`, which is backed by nothing on `wasm32-unknown-unknown` and by origin
+//! private storage (synchronous, but not visible to devtools or IndexedDB) on `wasm32-wasi`.
+//! Retargeting `io.FileIO` onto an in-memory store is itself a sizeable change (every read/write/
+//! seek call site in `vm/src/stdlib/io.rs` would need to go through a VM-level indirection instead
+//! of a raw `fs::File`), so this module only introduces the store and its trait; wiring it in is
+//! left for a follow-up.
+//!
+//! IndexedDB (and OPFS outside of a worker) persistence specifically is not implemented here
+//! either, for a more fundamental reason than "not wired up yet": both are asynchronous
+//! (`IDBRequest`/`FileSystemFileHandle::getFile` return promises), while `Vfs` below -- and the
+//! synchronous `std::io::{Read, Write, Seek}` traits that `io.FileIO` is built on -- are not. A
+//! real implementation needs either OPFS's synchronous worker-only access handles
+//! (`createSyncAccessHandle`, only available off the main thread) or the same stack-suspension
+//! mechanism [`crate::blocking`] documents as missing for `time.sleep`. Until one of those lands,
+//! [`InMemoryVfs`] is the only backend, and it persists only as long as the `WASMVirtualMachine`
+//! it's attached to.
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+/// A pluggable backing store for files used by a `rustpython_wasm` instance.
+///
+/// Paths are opaque byte-free strings (no normalization, no directories) deliberately: this is
+/// the minimum surface a backend needs to support `open()`-style whole-file read/write, not a
+/// full filesystem. See the module docs for what's missing on top of this to back `os`/`io`
+/// directly.
+pub trait Vfs {
+    fn read(&self, path: &str) -> Option<Vec<u8>>;
+    fn write(&self, path: &str, data: Vec<u8>);
+    fn remove(&self, path: &str) -> bool;
+}
+
+/// The default, and currently only, [`Vfs`] backend: plain in-memory storage with no persistence
+/// across page reloads.
+#[derive(Default, Clone)]
+pub struct InMemoryVfs {
+    files: Arc<Mutex<HashMap<String, Vec<u8>>>>,
+}
+
+impl Vfs for InMemoryVfs {
+    fn read(&self, path: &str) -> Option<Vec<u8>> {
+        self.files.lock().unwrap().get(path).cloned()
+    }
+
+    fn write(&self, path: &str, data: Vec<u8>) {
+        self.files.lock().unwrap().insert(path.to_owned(), data);
+    }
+
+    fn remove(&self, path: &str) -> bool {
+        self.files.lock().unwrap().remove(path).is_some()
+    }
+}